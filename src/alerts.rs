@@ -0,0 +1,122 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use crate::process::ProcessInfo;
+
+/// A predicate over a process's current resource usage.
+///
+/// Matchers are intentionally small and composable, mirroring the
+/// matcher/tracker split in pswatch: a `StateMatcher` answers "does this
+/// process satisfy the condition *right now*", and a [`StateTracker`] adds the
+/// sustained-over-time semantics on top.
+pub trait StateMatcher {
+    /// Whether `process` currently satisfies the condition.
+    fn matches(&self, process: &ProcessInfo) -> bool;
+}
+
+/// Matches processes whose CPU usage is at or above `threshold` percent.
+pub struct CpuMatcher {
+    pub threshold: f32,
+}
+
+impl StateMatcher for CpuMatcher {
+    fn matches(&self, process: &ProcessInfo) -> bool {
+        process.cpu_percent >= self.threshold
+    }
+}
+
+/// Matches processes whose resident memory is at or above `threshold_mb`.
+pub struct MemoryMatcher {
+    pub threshold_mb: f64,
+}
+
+impl StateMatcher for MemoryMatcher {
+    fn matches(&self, process: &ProcessInfo) -> bool {
+        process.memory_mb >= self.threshold_mb
+    }
+}
+
+/// Whether an alert marks a process newly entering or leaving the alert state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    /// The condition has held long enough and the process is now alerting.
+    Crossed,
+    /// The condition lapsed and the process is no longer alerting.
+    Cleared,
+}
+
+/// A transition emitted by [`StateTracker::poll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alert {
+    pub pid: u32,
+    pub kind: AlertKind,
+}
+
+/// Tracks which processes have sustained a matcher's condition for at least a
+/// minimum duration, emitting an alert only on the transitions.
+///
+/// A process must keep matching across consecutive [`poll`](Self::poll) calls;
+/// any lapse resets its timer, so a brief spike never fires.
+pub struct StateTracker<M: StateMatcher> {
+    matcher: M,
+    min_duration: Duration,
+    /// When each currently-matching process first matched.
+    first_match: HashMap<u32, Instant>,
+    /// Processes currently in the alerting state.
+    firing: HashSet<u32>,
+}
+
+impl<M: StateMatcher> StateTracker<M> {
+    pub fn new(matcher: M, min_duration: Duration) -> Self {
+        Self {
+            matcher,
+            min_duration,
+            first_match: HashMap::new(),
+            firing: HashSet::new(),
+        }
+    }
+
+    /// Feed the latest snapshot and return the processes that newly crossed into
+    /// or cleared out of the alert state since the previous call.
+    pub fn poll(&mut self, processes: &[ProcessInfo]) -> Vec<Alert> {
+        let now = Instant::now();
+        let matched: HashSet<u32> = processes
+            .iter()
+            .filter(|p| self.matcher.matches(p))
+            .map(|p| p.pid)
+            .collect();
+
+        let mut alerts = Vec::new();
+
+        // Newly crossing: matched long enough and not already firing.
+        for &pid in &matched {
+            let first = *self.first_match.entry(pid).or_insert(now);
+            if now.duration_since(first) >= self.min_duration && self.firing.insert(pid) {
+                alerts.push(Alert {
+                    pid,
+                    kind: AlertKind::Crossed,
+                });
+            }
+        }
+
+        // Any process that stopped matching resets its timer...
+        self.first_match.retain(|pid, _| matched.contains(pid));
+
+        // ...and clears if it was firing.
+        let cleared: Vec<u32> = self
+            .firing
+            .iter()
+            .filter(|pid| !matched.contains(pid))
+            .copied()
+            .collect();
+        for pid in cleared {
+            self.firing.remove(&pid);
+            alerts.push(Alert {
+                pid,
+                kind: AlertKind::Cleared,
+            });
+        }
+
+        alerts
+    }
+}
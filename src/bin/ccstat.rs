@@ -0,0 +1,17 @@
+//! Entry point for the process/task monitor (`cli`/`config`/`db`/`process`/
+//! `ui`/`monitor` stack), kept as a separate binary from `ccmonitor`'s
+//! timeline TUI in `main.rs` since the two tools have unrelated `Args`/`Cli`
+//! shapes and lifecycles.
+use ccmonitor::{cli::Cli, monitor::Monitor};
+use clap::Parser;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    if cli.summary {
+        Monitor::from_cli(&cli)?.show_summary().await
+    } else {
+        Monitor::from_cli(&cli)?.run().await
+    }
+}
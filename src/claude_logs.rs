@@ -3,10 +3,17 @@ use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::Stream;
 
-use crate::git_utils::get_repository_name;
+use crate::git_utils::{get_parent_project_name, get_repository_info};
+
+/// How often `tail_sessions` re-scans `~/.claude/projects` for growth. Short
+/// enough to feel live in a TUI, long enough not to busy-loop on an idle tree.
+const TAIL_POLL_INTERVAL: Duration = Duration::from_millis(500);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionEvent {
@@ -18,9 +25,11 @@ pub struct SessionEvent {
     pub uuid: String,
     pub input_tokens: u32,
     pub output_tokens: u32,
+    /// Model that produced this event, when reported by the log.
+    pub model: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SessionTimeline {
     pub session_id: String,
     pub directory: String,
@@ -51,6 +60,7 @@ struct LogMessage {
     role: Option<String>,
     content: Option<serde_json::Value>,
     usage: Option<TokenUsage>,
+    model: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -78,12 +88,12 @@ impl SessionEvent {
         let directory = entry.cwd.unwrap_or_default();
         let uuid = entry.uuid.unwrap_or_default();
 
-        let (message_type, content_preview, input_tokens, output_tokens) = 
+        let (message_type, content_preview, input_tokens, output_tokens, model) =
             if let Some(message) = entry.message {
-                let role = message.role.unwrap_or_else(|| 
+                let role = message.role.unwrap_or_else(||
                     entry.entry_type.unwrap_or_else(|| "unknown".to_string())
                 );
-                
+
                 let content = Self::extract_content_text(&message.content);
                 let content_preview = if content.len() > 100 {
                     format!("{}...", &content[..100])
@@ -104,9 +114,9 @@ impl SessionEvent {
                     (0, 0)
                 };
 
-                (role, content_preview, input_tokens, output_tokens)
+                (role, content_preview, input_tokens, output_tokens, message.model)
             } else {
-                ("unknown".to_string(), String::new(), 0, 0)
+                ("unknown".to_string(), String::new(), 0, 0, None)
             };
 
         Some(SessionEvent {
@@ -118,6 +128,7 @@ impl SessionEvent {
             uuid,
             input_tokens,
             output_tokens,
+            model,
         })
     }
 
@@ -172,6 +183,180 @@ pub fn parse_jsonl_file<P: AsRef<Path>>(file_path: P) -> Result<Vec<SessionEvent
     Ok(events)
 }
 
+/// Parse only the lines appended to `file_path` since `start_offset`, returning
+/// the new events alongside the byte offset to resume from next time.
+///
+/// A trailing line with no terminating `\n` is assumed to still be mid-write
+/// and is left unconsumed, so the returned offset never points past a
+/// complete line.
+pub fn parse_jsonl_from_offset<P: AsRef<Path>>(
+    file_path: P,
+    start_offset: u64,
+) -> Result<(Vec<SessionEvent>, u64)> {
+    let mut file = File::open(&file_path)
+        .with_context(|| format!("Failed to open file: {}", file_path.as_ref().display()))?;
+
+    file.seek(SeekFrom::Start(start_offset))
+        .with_context(|| format!("Failed to seek {}", file_path.as_ref().display()))?;
+
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)
+        .with_context(|| format!("Failed to read {}", file_path.as_ref().display()))?;
+
+    let mut events = Vec::new();
+    let mut consumed = 0u64;
+
+    for line in buf.split_inclusive(|&b| b == b'\n') {
+        if line.last() != Some(&b'\n') {
+            // Partial line at EOF; wait for the next ingest pass.
+            break;
+        }
+
+        consumed += line.len() as u64;
+        let line = String::from_utf8_lossy(&line[..line.len() - 1]);
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry: LogEntry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(_) => continue, // Skip malformed lines rather than aborting the whole file.
+        };
+
+        if let Some(event) = SessionEvent::from_log_entry(entry) {
+            events.push(event);
+        }
+    }
+
+    Ok((events, start_offset + consumed))
+}
+
+/// Recursively find every `.jsonl` file under a Claude projects directory.
+pub(crate) fn find_jsonl_files(claude_dir: &Path) -> Vec<PathBuf> {
+    walkdir::WalkDir::new(claude_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && entry.path().extension().map(|ext| ext == "jsonl").unwrap_or(false)
+        })
+        .map(|entry| entry.path().to_path_buf())
+        .collect()
+}
+
+/// Group already-parsed events into per-session timelines, applying the same
+/// project-naming and filtering rules as [`load_sessions_in_timerange`].
+pub fn group_events_into_timelines(
+    all_events: Vec<SessionEvent>,
+    project_filter: Option<&str>,
+    threads: bool,
+) -> Vec<SessionTimeline> {
+    let mut session_groups: HashMap<(String, String), Vec<SessionEvent>> = HashMap::new();
+
+    for event in all_events {
+        let key = (event.session_id.clone(), event.directory.clone());
+        session_groups.entry(key).or_default().push(event);
+    }
+
+    let mut timelines = Vec::new();
+
+    for ((session_id, directory), mut events) in session_groups {
+        events.sort_by_key(|e| e.timestamp);
+
+        if events.is_empty() {
+            continue;
+        }
+
+        let start_time = events.first().unwrap().timestamp;
+        let end_time = events.last().unwrap().timestamp;
+
+        // In threads mode, key the project by host/owner/name so two distinct
+        // repos that merely share a short name are not collapsed together.
+        let project_name = match get_repository_info(&directory) {
+            Some(info) if threads => info.thread_key(),
+            Some(info) => info.name,
+            None => Path::new(&directory)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown")
+                .to_string(),
+        };
+
+        // Apply project filter
+        if let Some(filter) = project_filter {
+            if !project_name.contains(filter) {
+                continue;
+            }
+        }
+
+        let active_duration_minutes = calculate_active_duration(&events);
+        let (total_input_tokens, total_output_tokens) = calculate_token_totals(&events);
+        let parent_project = get_parent_project_name(&directory);
+
+        let timeline = SessionTimeline {
+            session_id,
+            directory,
+            project_name,
+            events,
+            start_time,
+            end_time,
+            active_duration_minutes,
+            parent_project,
+            total_input_tokens,
+            total_output_tokens,
+        };
+
+        timelines.push(timeline);
+    }
+
+    // Sort timelines by start time
+    timelines.sort_by_key(|t| t.start_time);
+
+    timelines
+}
+
+/// Aggregate token and active-duration totals for sessions that roll up
+/// under an enclosing repository (worktrees/submodules), keyed by the parent's
+/// project name. Timelines without a `parent_project` aren't part of a
+/// hierarchy and are omitted; per-`project_name` timelines are untouched, so
+/// both the rollup and the individual breakdown stay available.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParentProjectTotals {
+    pub parent_project: String,
+    pub child_count: usize,
+    pub total_input_tokens: u32,
+    pub total_output_tokens: u32,
+    pub active_duration_minutes: u32,
+}
+
+pub fn aggregate_parent_projects(timelines: &[SessionTimeline]) -> Vec<ParentProjectTotals> {
+    let mut totals: HashMap<String, ParentProjectTotals> = HashMap::new();
+
+    for timeline in timelines {
+        let Some(parent) = &timeline.parent_project else {
+            continue;
+        };
+
+        let entry = totals.entry(parent.clone()).or_insert_with(|| ParentProjectTotals {
+            parent_project: parent.clone(),
+            child_count: 0,
+            total_input_tokens: 0,
+            total_output_tokens: 0,
+            active_duration_minutes: 0,
+        });
+
+        entry.child_count += 1;
+        entry.total_input_tokens += timeline.total_input_tokens;
+        entry.total_output_tokens += timeline.total_output_tokens;
+        entry.active_duration_minutes += timeline.active_duration_minutes;
+    }
+
+    let mut totals: Vec<_> = totals.into_values().collect();
+    totals.sort_by(|a, b| a.parent_project.cmp(&b.parent_project));
+    totals
+}
+
 pub fn calculate_active_duration(events: &[SessionEvent]) -> u32 {
     if events.is_empty() {
         return 0;
@@ -209,7 +394,7 @@ pub fn load_sessions_in_timerange(
     start_time: DateTime<Local>,
     end_time: DateTime<Local>,
     project_filter: Option<&str>,
-    _threads: bool,
+    threads: bool,
 ) -> Result<Vec<SessionTimeline>> {
     let claude_dir = dirs::home_dir()
         .context("Could not find home directory")?
@@ -222,89 +407,90 @@ pub fn load_sessions_in_timerange(
     let mut all_events = Vec::new();
 
     // Recursively find all .jsonl files
-    for entry in walkdir::WalkDir::new(&claude_dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-    {
-        if entry.file_type().is_file() {
-            if let Some(extension) = entry.path().extension() {
-                if extension == "jsonl" {
-                    match parse_jsonl_file(entry.path()) {
-                        Ok(mut events) => {
-                            // Filter events by time range
-                            events.retain(|event| {
-                                event.timestamp >= start_time && event.timestamp <= end_time
-                            });
-                            all_events.extend(events);
-                        }
-                        Err(_) => {
-                            // Skip files that can't be parsed
-                            continue;
-                        }
-                    }
-                }
+    for path in find_jsonl_files(&claude_dir) {
+        match parse_jsonl_file(&path) {
+            Ok(mut events) => {
+                // Filter events by time range
+                events.retain(|event| event.timestamp >= start_time && event.timestamp <= end_time);
+                all_events.extend(events);
+            }
+            Err(_) => {
+                // Skip files that can't be parsed
+                continue;
             }
         }
     }
 
-    // Group events by session and directory
-    let mut session_groups: HashMap<(String, String), Vec<SessionEvent>> = HashMap::new();
-    
-    for event in all_events {
-        let key = (event.session_id.clone(), event.directory.clone());
-        session_groups.entry(key).or_default().push(event);
-    }
-
-    let mut timelines = Vec::new();
+    Ok(group_events_into_timelines(all_events, project_filter, threads))
+}
 
-    for ((session_id, directory), mut events) in session_groups {
-        // Sort events by timestamp
-        events.sort_by_key(|e| e.timestamp);
+/// Tail `~/.claude/projects` for appended log lines and yield each new
+/// [`SessionEvent`] as it lands, in timestamp order.
+///
+/// This starts watching from the current end of every file that exists when
+/// the stream is created — it reflects an in-progress session rather than
+/// replaying history, which [`load_sessions_in_timerange`] already covers.
+/// Files created after the stream starts are picked up from their first byte
+/// the next time they're seen. A trailing line with no `\n` yet (a write in
+/// progress) is left unconsumed until it completes, exactly like
+/// [`parse_jsonl_from_offset`].
+pub fn tail_sessions() -> impl Stream<Item = SessionEvent> {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let Some(claude_dir) = dirs::home_dir().map(|home| home.join(".claude/projects")) else {
+            return;
+        };
 
-        if events.is_empty() {
-            continue;
-        }
+        // Files that already exist when the stream starts are seeded at EOF so
+        // we only tail what's appended from here on; anything discovered later
+        // (in the loop below) is genuinely new and starts at its first byte.
+        let mut offsets: HashMap<PathBuf, u64> = if claude_dir.exists() {
+            find_jsonl_files(&claude_dir)
+                .into_iter()
+                .map(|path| {
+                    let eof = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                    (path, eof)
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        };
+        let mut ticker = tokio::time::interval(TAIL_POLL_INTERVAL);
 
-        let start_time = events.first().unwrap().timestamp;
-        let end_time = events.last().unwrap().timestamp;
-        
-        let project_name = get_repository_name(&directory)
-            .unwrap_or_else(|| {
-                Path::new(&directory)
-                    .file_name()
-                    .and_then(|name| name.to_str())
-                    .unwrap_or("unknown")
-                    .to_string()
-            });
+        loop {
+            ticker.tick().await;
 
-        // Apply project filter
-        if let Some(filter) = project_filter {
-            if !project_name.contains(filter) {
+            if !claude_dir.exists() {
                 continue;
             }
-        }
 
-        let active_duration_minutes = calculate_active_duration(&events);
-        let (total_input_tokens, total_output_tokens) = calculate_token_totals(&events);
+            let mut new_events = Vec::new();
 
-        let timeline = SessionTimeline {
-            session_id,
-            directory,
-            project_name,
-            events,
-            start_time,
-            end_time,
-            active_duration_minutes,
-            parent_project: None, // TODO: Implement parent project grouping
-            total_input_tokens,
-            total_output_tokens,
-        };
+            for path in find_jsonl_files(&claude_dir) {
+                let start_offset = *offsets.entry(path.clone()).or_insert(0);
 
-        timelines.push(timeline);
-    }
+                match parse_jsonl_from_offset(&path, start_offset) {
+                    Ok((events, new_offset)) => {
+                        offsets.insert(path, new_offset);
+                        new_events.extend(events);
+                    }
+                    Err(e) => {
+                        crate::diag!("Failed to tail {}: {}", path.display(), e);
+                    }
+                }
+            }
 
-    // Sort timelines by start time
-    timelines.sort_by_key(|t| t.start_time);
+            new_events.sort_by_key(|event| event.timestamp);
+
+            for event in new_events {
+                if tx.send(event).is_err() {
+                    // Receiver dropped; stop tailing.
+                    return;
+                }
+            }
+        }
+    });
 
-    Ok(timelines)
+    UnboundedReceiverStream::new(rx)
 }
\ No newline at end of file
@@ -6,4 +6,39 @@ pub struct Cli {
     /// Show summary only (no real-time monitoring)
     #[arg(short, long)]
     pub summary: bool,
+
+    /// Basic mode: no charts, condensed layout (good for narrow terminals)
+    #[arg(short, long)]
+    pub basic: bool,
+
+    /// Path to a TOML config file (defaults to ~/.ccstat/config.toml)
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<std::path::PathBuf>,
+
+    /// Serve process and session metrics in Prometheus format on this address
+    /// (e.g. `127.0.0.1:9185`) for scraping into Grafana.
+    #[arg(long, value_name = "HOST:PORT")]
+    pub metrics_addr: Option<std::net::SocketAddr>,
+
+    /// Maximum age, in days, of retained rows; overrides the config file
+    #[arg(long, value_name = "DAYS")]
+    pub max_age_days: Option<u32>,
+
+    /// Total on-disk byte budget for the database; oldest rows are evicted
+    /// once it's exceeded
+    #[arg(long, value_name = "BYTES")]
+    pub max_db_bytes: Option<u64>,
+
+    /// Maximum retained sessions per project; older sessions are evicted
+    #[arg(long, value_name = "N")]
+    pub max_sessions_per_project: Option<u32>,
+
+    /// Maximum retained events per session; older events are evicted
+    #[arg(long, value_name = "N")]
+    pub max_events_per_session: Option<u32>,
+
+    /// Only show processes matching a query expression, e.g. `cpu >= 5` or
+    /// `runtime > 30m`
+    #[arg(long, value_name = "EXPR")]
+    pub filter: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,134 @@
+use anyhow::{Context, Result};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::utils::get_data_dir;
+
+/// Persisted startup preferences, loaded from `config.toml` under
+/// [`get_data_dir`] (the same base directory as the database and the
+/// pricing table).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Tab shown on startup: `overview`, `processes` or `tasks`.
+    pub default_tab: String,
+    /// Data-collection interval in milliseconds (the UI redraws on its own,
+    /// faster tick).
+    pub refresh_interval_ms: u64,
+    /// Minutes of history shown in the CPU/memory charts.
+    pub history_minutes: u32,
+    /// Days of stored metrics to keep before cleanup.
+    pub data_retention_days: u32,
+    /// CPU percent a process must sustain to raise an alert.
+    pub cpu_alert_percent: f32,
+    /// Resident memory (MB) a process must sustain to raise an alert.
+    pub memory_alert_mb: f64,
+    /// How long a process must stay over a threshold before the alert fires.
+    pub alert_sustain_secs: u64,
+    /// Accent colors for the UI.
+    pub colors: Colors,
+}
+
+/// Accent colors, stored as names (e.g. `cyan`) or `#rrggbb` hex.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Colors {
+    pub primary: String,
+    pub accent: String,
+    pub alert: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_tab: "overview".to_string(),
+            refresh_interval_ms: 1000,
+            history_minutes: 5,
+            data_retention_days: 7,
+            cpu_alert_percent: 80.0,
+            memory_alert_mb: 1024.0,
+            alert_sustain_secs: 30,
+            colors: Colors::default(),
+        }
+    }
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            primary: "cyan".to_string(),
+            accent: "yellow".to_string(),
+            alert: "red".to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// Load config from `path` (or the default location), creating the file with
+    /// defaults when it does not yet exist.
+    pub fn load(path: Option<PathBuf>) -> Result<Self> {
+        let path = match path {
+            Some(p) => p,
+            None => Self::default_path()?,
+        };
+
+        if !path.exists() {
+            let defaults = Self::default();
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            if let Ok(serialized) = toml::to_string_pretty(&defaults) {
+                std::fs::write(&path, serialized).ok();
+            }
+            return Ok(defaults);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config: {}", path.display()))?;
+        let config: Config = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse config: {}", path.display()))?;
+        Ok(config)
+    }
+
+    fn default_path() -> Result<PathBuf> {
+        let mut path = get_data_dir()?;
+        path.push("config.toml");
+        Ok(path)
+    }
+
+    /// Map `default_tab` to its tab index.
+    pub fn default_tab_index(&self) -> usize {
+        match self.default_tab.to_lowercase().as_str() {
+            "processes" => 1,
+            "tasks" => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// Parse a color name or `#rrggbb` hex string, falling back to `default`.
+pub fn parse_color(value: &str, default: Color) -> Color {
+    let v = value.trim().to_lowercase();
+    if let Some(hex) = v.strip_prefix('#') {
+        if hex.len() == 6 {
+            if let Ok(rgb) = u32::from_str_radix(hex, 16) {
+                return Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8);
+            }
+        }
+        return default;
+    }
+    match v.as_str() {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" | "grey" => Color::Gray,
+        "darkgray" | "darkgrey" => Color::DarkGray,
+        "white" => Color::White,
+        _ => default,
+    }
+}
@@ -4,27 +4,32 @@ use duckdb::{params, Connection};
 use std::fs;
 use std::path::PathBuf;
 
+use crate::claude_logs::{find_jsonl_files, group_events_into_timelines, parse_jsonl_from_offset, SessionEvent, SessionTimeline};
 use crate::process::ProcessInfo;
 use crate::utils::get_data_dir;
 
 pub struct Database {
     conn: Connection,
+    db_path: PathBuf,
 }
 
 impl Database {
-    /// Create a new database connection
+    /// Create a new database connection under [`get_data_dir`].
     pub fn new() -> Result<Self> {
         let data_dir = get_data_dir()?;
-        
-        // Create directory if it doesn't exist
         fs::create_dir_all(&data_dir)?;
-        
-        let db_path = data_dir.join("data.db");
+        Self::open(data_dir.join("data.db"))
+    }
+
+    /// Open (creating if needed) a database at a specific path. `new()` is the
+    /// production entry point; this is the seam tests use to open an isolated
+    /// database instead of touching `~/.ccstat`.
+    pub fn open(db_path: PathBuf) -> Result<Self> {
         let conn = Connection::open(&db_path)?;
-        
-        let mut db = Self { conn };
+
+        let mut db = Self { conn, db_path };
         db.init_schema()?;
-        
+
         Ok(db)
     }
 
@@ -56,6 +61,41 @@ impl Database {
             [],
         )?;
 
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS session_events (
+                id INTEGER PRIMARY KEY,
+                session_id TEXT NOT NULL,
+                directory TEXT NOT NULL,
+                timestamp TIMESTAMP NOT NULL,
+                message_type TEXT NOT NULL,
+                content_preview TEXT NOT NULL,
+                uuid TEXT NOT NULL,
+                input_tokens INTEGER NOT NULL,
+                output_tokens INTEGER NOT NULL,
+                model TEXT
+            )",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_session_events_timestamp ON session_events(timestamp)",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_session_events_session_id ON session_events(session_id)",
+            [],
+        )?;
+
+        self.conn.execute(
+            "CREATE TABLE IF NOT EXISTS ingested_files (
+                path TEXT PRIMARY KEY,
+                byte_offset INTEGER NOT NULL,
+                mtime TIMESTAMP NOT NULL
+            )",
+            [],
+        )?;
+
         Ok(())
     }
 
@@ -80,6 +120,118 @@ impl Database {
         Ok(())
     }
 
+    /// Parse any `.jsonl` bytes appended under `~/.claude/projects` since the
+    /// last call and persist them to `session_events`, so
+    /// [`Database::load_sessions_in_timerange`] can answer from an indexed
+    /// query instead of re-walking and re-parsing the whole directory.
+    pub fn ingest_claude_logs(&mut self) -> Result<()> {
+        let claude_dir = dirs::home_dir()
+            .ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?
+            .join(".claude/projects");
+
+        if !claude_dir.exists() {
+            return Ok(());
+        }
+
+        for path in find_jsonl_files(&claude_dir) {
+            let path_str = path.to_string_lossy().to_string();
+            let metadata = fs::metadata(&path)?;
+            let mtime: DateTime<Local> = metadata.modified()?.into();
+
+            let stored_offset: Option<i64> = self
+                .conn
+                .query_row(
+                    "SELECT byte_offset FROM ingested_files WHERE path = ?",
+                    params![path_str],
+                    |row| row.get(0),
+                )
+                .ok();
+            let start_offset = stored_offset.unwrap_or(0) as u64;
+
+            let (events, new_offset) = match parse_jsonl_from_offset(&path, start_offset) {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::error!("Failed to ingest {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            for event in &events {
+                self.insert_session_event(event)?;
+            }
+
+            self.conn.execute(
+                "INSERT INTO ingested_files (path, byte_offset, mtime) VALUES (?, ?, ?)
+                ON CONFLICT (path) DO UPDATE SET byte_offset = excluded.byte_offset, mtime = excluded.mtime",
+                params![path_str, new_offset as i64, mtime.naive_local()],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn insert_session_event(&mut self, event: &SessionEvent) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO session_events
+            (session_id, directory, timestamp, message_type, content_preview, uuid, input_tokens, output_tokens, model)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                &event.session_id,
+                &event.directory,
+                event.timestamp.naive_local(),
+                &event.message_type,
+                &event.content_preview,
+                &event.uuid,
+                event.input_tokens,
+                event.output_tokens,
+                &event.model,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Load session timelines for a time range from the `session_events`
+    /// table ingested by [`Database::ingest_claude_logs`], avoiding a full
+    /// filesystem re-scan on every call.
+    pub fn load_sessions_in_timerange(
+        &self,
+        start_time: DateTime<Local>,
+        end_time: DateTime<Local>,
+        project_filter: Option<&str>,
+        threads: bool,
+    ) -> Result<Vec<SessionTimeline>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT session_id, directory, timestamp, message_type, content_preview, uuid, input_tokens, output_tokens, model
+            FROM session_events
+            WHERE timestamp BETWEEN ? AND ?",
+        )?;
+
+        let events = stmt
+            .query_map(
+                params![start_time.naive_local(), end_time.naive_local()],
+                |row| {
+                    Ok(SessionEvent {
+                        session_id: row.get(0)?,
+                        directory: row.get(1)?,
+                        timestamp: DateTime::from_naive_utc_and_offset(
+                            row.get::<_, NaiveDateTime>(2)?,
+                            *Local::now().offset(),
+                        ),
+                        message_type: row.get(3)?,
+                        content_preview: row.get(4)?,
+                        uuid: row.get(5)?,
+                        input_tokens: row.get(6)?,
+                        output_tokens: row.get(7)?,
+                        model: row.get(8)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(group_events_into_timelines(events, project_filter, threads))
+    }
+
     /// Get process statistics for a time range
     pub fn get_process_stats(
         &self,
@@ -226,9 +378,115 @@ impl Database {
 
         Ok(())
     }
+
+    /// Enforce `policy`'s caps, evicting the oldest rows first, in the order:
+    /// age, per-session event count, per-project session count, then the
+    /// total byte budget. A final `VACUUM` reclaims the space freed by all of
+    /// them; the byte-budget step also runs `VACUUM` after each eviction pass
+    /// so it can re-measure the file.
+    pub fn enforce_retention(&mut self, policy: &RetentionPolicy) -> Result<()> {
+        self.conn.execute(
+            &format!(
+                "DELETE FROM process_metrics WHERE timestamp < datetime('now', '-{} days')",
+                policy.max_age_days
+            ),
+            [],
+        )?;
+        self.conn.execute(
+            &format!(
+                "DELETE FROM session_events WHERE timestamp < datetime('now', '-{} days')",
+                policy.max_age_days
+            ),
+            [],
+        )?;
+
+        if let Some(max_events) = policy.max_events_per_session {
+            // Keep each session's newest `max_events`, dropping the rest.
+            self.conn.execute(
+                "DELETE FROM session_events
+                WHERE id IN (
+                    SELECT id FROM (
+                        SELECT id, ROW_NUMBER() OVER (
+                            PARTITION BY session_id ORDER BY timestamp DESC
+                        ) AS rn
+                        FROM session_events
+                    )
+                    WHERE rn > ?
+                )",
+                params![max_events],
+            )?;
+        }
+
+        if let Some(max_sessions) = policy.max_sessions_per_project {
+            // "Project" is approximated by `directory`, the only grouping key
+            // session_events carries; keep each directory's most-recently-active
+            // sessions.
+            self.conn.execute(
+                "DELETE FROM session_events
+                WHERE session_id IN (
+                    SELECT session_id FROM (
+                        SELECT session_id, directory, ROW_NUMBER() OVER (
+                            PARTITION BY directory ORDER BY MAX(timestamp) DESC
+                        ) AS rn
+                        FROM session_events
+                        GROUP BY session_id, directory
+                    )
+                    WHERE rn > ?
+                )",
+                params![max_sessions],
+            )?;
+        }
+
+        if let Some(max_bytes) = policy.max_total_bytes {
+            // DuckDB doesn't expose a cheap "bytes freed by this delete"
+            // figure, so trim in fixed-size passes and re-check the file size
+            // after each `VACUUM` rather than trying to compute an exact row count.
+            const EVICTION_BATCH: i64 = 1000;
+            let mut guard = 0;
+            while self.on_disk_bytes()? > max_bytes && guard < 100 {
+                self.conn.execute(
+                    "DELETE FROM process_metrics WHERE id IN (
+                        SELECT id FROM process_metrics ORDER BY timestamp ASC LIMIT ?
+                    )",
+                    params![EVICTION_BATCH],
+                )?;
+                self.conn.execute(
+                    "DELETE FROM session_events WHERE id IN (
+                        SELECT id FROM session_events ORDER BY timestamp ASC LIMIT ?
+                    )",
+                    params![EVICTION_BATCH],
+                )?;
+                self.conn.execute("VACUUM", [])?;
+                guard += 1;
+            }
+        }
+
+        self.conn.execute("VACUUM", [])?;
+
+        Ok(())
+    }
+
+    /// Current size of the database file on disk.
+    fn on_disk_bytes(&self) -> Result<u64> {
+        Ok(fs::metadata(&self.db_path)?.len())
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Multiple caps enforced together by [`Database::enforce_retention`], so a
+/// long-running collector stays bounded by more than just a day count.
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPolicy {
+    /// Total on-disk byte budget for the database file.
+    pub max_total_bytes: Option<u64>,
+    /// Maximum retained sessions per project (approximated by `directory`).
+    pub max_sessions_per_project: Option<u32>,
+    /// Maximum retained events per session.
+    pub max_events_per_session: Option<u32>,
+    /// Maximum age, in days, of any retained row.
+    pub max_age_days: u32,
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct ProcessStats {
     pub process_count: i32,
     pub avg_cpu: f32,
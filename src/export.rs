@@ -0,0 +1,132 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+
+use crate::claude_logs::SessionTimeline;
+
+/// Output formats for `--export`, as distinct from `--format`'s human/CSV
+/// leaning: these are meant to feed an external visualizer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// A flat JSON array of `SessionTimeline`
+    Json,
+    /// Chrome Tracing / Perfetto "trace event" JSON
+    Trace,
+}
+
+/// One entry in the Chrome Trace Event Format. `ph` selects the event kind
+/// ("X" complete, "i" instant, "M" metadata); fields that don't apply to a
+/// given kind are simply omitted.
+#[derive(Serialize)]
+struct TraceEvent {
+    name: String,
+    cat: String,
+    ph: &'static str,
+    ts: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s: Option<&'static str>,
+    pid: u64,
+    tid: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<serde_json::Value>,
+}
+
+/// Serialize `timelines` per `format` for downstream tooling.
+pub fn export_timelines(timelines: &[SessionTimeline], format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(timelines)?),
+        ExportFormat::Trace => Ok(serde_json::to_string_pretty(&build_trace(timelines))?),
+    }
+}
+
+/// Stable id for a trace pid/tid: Chrome's format wants a number, but we key
+/// ours by name, so hash the name into one instead of minting sequential ids.
+fn track_id(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn build_trace(timelines: &[SessionTimeline]) -> serde_json::Value {
+    let mut events = Vec::new();
+    let mut labeled_pids = HashSet::new();
+    let mut labeled_tids = HashSet::new();
+
+    for timeline in timelines {
+        let pid = track_id(&timeline.project_name);
+        let tid = track_id(&timeline.session_id);
+
+        if labeled_pids.insert(pid) {
+            events.push(TraceEvent {
+                name: "process_name".to_string(),
+                cat: "__metadata".to_string(),
+                ph: "M",
+                ts: 0,
+                dur: None,
+                s: None,
+                pid,
+                tid,
+                args: Some(json!({ "name": timeline.project_name })),
+            });
+        }
+
+        if labeled_tids.insert((pid, tid)) {
+            events.push(TraceEvent {
+                name: "thread_name".to_string(),
+                cat: "__metadata".to_string(),
+                ph: "M",
+                ts: 0,
+                dur: None,
+                s: None,
+                pid,
+                tid,
+                args: Some(json!({ "name": timeline.session_id })),
+            });
+        }
+
+        let dur = (timeline.end_time - timeline.start_time)
+            .num_microseconds()
+            .unwrap_or(0);
+
+        events.push(TraceEvent {
+            name: timeline.session_id.clone(),
+            cat: "session".to_string(),
+            ph: "X",
+            ts: timeline.start_time.timestamp_micros(),
+            dur: Some(dur),
+            s: None,
+            pid,
+            tid,
+            args: Some(json!({
+                "active_duration_minutes": timeline.active_duration_minutes,
+                "input_tokens": timeline.total_input_tokens,
+                "output_tokens": timeline.total_output_tokens,
+            })),
+        });
+
+        for event in &timeline.events {
+            events.push(TraceEvent {
+                name: event.message_type.clone(),
+                cat: "event".to_string(),
+                ph: "i",
+                ts: event.timestamp.timestamp_micros(),
+                dur: None,
+                s: Some("t"),
+                pid,
+                tid,
+                args: Some(json!({
+                    "input_tokens": event.input_tokens,
+                    "output_tokens": event.output_tokens,
+                })),
+            });
+        }
+    }
+
+    json!({ "traceEvents": events })
+}
@@ -1,9 +1,43 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+/// Repository coordinates parsed from a git remote URL.
+///
+/// `host` and `owner` are optional because bare local paths (`/srv/git/repo.git`)
+/// carry neither, but `name` is always present for a usable URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoInfo {
+    pub host: Option<String>,
+    pub owner: Option<String>,
+    pub name: String,
+}
+
+impl RepoInfo {
+    /// A stable identifier combining host, owner and name.
+    ///
+    /// Used for `--threads` grouping so that two repositories sharing only a
+    /// short name (e.g. `user-a/api` and `user-b/api`) are not merged.
+    pub fn thread_key(&self) -> String {
+        match (&self.host, &self.owner) {
+            (Some(host), Some(owner)) => format!("{}/{}/{}", host, owner, self.name),
+            (None, Some(owner)) => format!("{}/{}", owner, self.name),
+            _ => self.name.clone(),
+        }
+    }
+}
+
+/// Resolve the repository name for a working directory, or `None` when it is
+/// not a git repository.
 pub fn get_repository_name(directory: &str) -> Option<String> {
+    get_repository_info(directory).map(|info| info.name)
+}
+
+/// Resolve full repository coordinates (host/owner/name) for a working
+/// directory from the first parseable `url` entry in its git config (the
+/// `origin` remote when it is listed first); other remotes are not told apart.
+pub fn get_repository_info(directory: &str) -> Option<RepoInfo> {
     let git_path = Path::new(directory).join(".git");
-    
+
     if !git_path.exists() {
         return None;
     }
@@ -11,10 +45,10 @@ pub fn get_repository_name(directory: &str) -> Option<String> {
     let config_file = if git_path.is_file() {
         // Handle git worktree case where .git is a file
         let git_content = fs::read_to_string(&git_path).ok()?;
-        
+
         if git_content.starts_with("gitdir: ") {
             let actual_git_dir = Path::new(&git_content.trim()[8..]);
-            
+
             // For worktree, check if commondir exists to find main git dir
             let commondir_file = actual_git_dir.join("commondir");
             if commondir_file.exists() {
@@ -37,18 +71,14 @@ pub fn get_repository_name(directory: &str) -> Option<String> {
     }
 
     let content = fs::read_to_string(&config_file).ok()?;
-    
+
     // Look for remote origin URL
     for line in content.lines() {
         if line.trim().starts_with("url = ") {
             let url = line.trim().strip_prefix("url = ")?.trim();
-            
-            // Extract repo name from various URL formats
-            // SSH format: git@github.com:user/repo.git
-            // HTTPS format: https://github.com/user/repo.git
-            
-            if let Some(repo_name) = extract_repo_name_from_url(url) {
-                return Some(repo_name);
+
+            if let Some(info) = parse_git_url(url) {
+                return Some(info);
             }
         }
     }
@@ -56,22 +86,106 @@ pub fn get_repository_name(directory: &str) -> Option<String> {
     None
 }
 
-fn extract_repo_name_from_url(url: &str) -> Option<String> {
-    // SSH format: git@github.com:user/repo.git
-    if url.contains(':') && !url.starts_with("http") {
-        if let Some(repo_part) = url.split(':').nth(1) {
-            if let Some(repo_name) = repo_part.split('/').last() {
-                return Some(repo_name.strip_suffix(".git").unwrap_or(repo_name).to_string());
-            }
-        }
+/// Resolve the repository name that `directory` should roll up under, when it
+/// is itself a git worktree or submodule of an enclosing repository.
+///
+/// A worktree's or submodule's `.git` is a file pointing at a `gitdir` nested
+/// under the enclosing repo's own `.git` (as `.git/worktrees/<name>` or
+/// `.git/modules/<name>` respectively). Walking up from that `gitdir` to the
+/// nearest ancestor named `.git` lands on the enclosing repo's working
+/// directory in both cases. A plain repository's `.git` is a directory, not
+/// a gitdir-pointer file, so it has no parent.
+pub fn get_parent_project_name(directory: &str) -> Option<String> {
+    let git_path = Path::new(directory).join(".git");
+    if !git_path.is_file() {
+        return None;
     }
-    
-    // HTTPS format: https://github.com/user/repo.git
-    if url.starts_with("http") {
-        if let Some(repo_name) = url.split('/').last() {
-            return Some(repo_name.strip_suffix(".git").unwrap_or(repo_name).to_string());
+
+    let git_content = fs::read_to_string(&git_path).ok()?;
+    let gitdir = git_content.trim().strip_prefix("gitdir: ")?;
+
+    // `gitdir` is written relative to `directory` for submodules (e.g.
+    // `../../.git/modules/foo`) and is typically absolute for worktrees; resolve
+    // it against `directory` either way rather than the process's own CWD.
+    let resolved = Path::new(directory).join(gitdir);
+    let resolved = resolved.canonicalize().unwrap_or(resolved);
+
+    let parent_root = find_enclosing_repo_root(&resolved)?;
+
+    repository_name_for_root(&parent_root)
+}
+
+/// Walk up from `path` to the nearest ancestor named `.git`, returning its
+/// parent directory (the enclosing repository's working directory).
+fn find_enclosing_repo_root(path: &Path) -> Option<PathBuf> {
+    let mut current = Some(path);
+    while let Some(p) = current {
+        if p.file_name().map(|name| name == ".git").unwrap_or(false) {
+            return p.parent().map(|root| root.to_path_buf());
         }
+        current = p.parent();
     }
-    
     None
-}
\ No newline at end of file
+}
+
+/// Name a repository root by its remote URL (see [`get_repository_info`]) when
+/// configured, falling back to the directory's basename like the
+/// untracked-project fallback in `claude_logs::group_events_into_timelines`.
+fn repository_name_for_root(root: &Path) -> Option<String> {
+    let root_str = root.to_str()?;
+    get_repository_info(root_str)
+        .map(|info| info.name)
+        .or_else(|| root.file_name().and_then(|name| name.to_str()).map(str::to_string))
+}
+
+/// Parse a git remote URL into host/owner/name.
+///
+/// Handles the common forms and their awkward cases:
+/// - scheme URLs with optional user and port: `ssh://git@host:2222/user/repo.git`
+/// - scp-like SSH: `git@github.com:user/repo.git`
+/// - self-hosted subgroups: `git@gitlab.com:group/subgroup/repo.git`
+/// - bare local paths: `/srv/git/repo.git` (no host or owner)
+pub fn parse_git_url(url: &str) -> Option<RepoInfo> {
+    let url = url.trim();
+
+    let (host, path): (Option<String>, String) = if let Some(idx) = url.find("://") {
+        // scheme://[user@]host[:port]/owner/.../name
+        let rest = &url[idx + 3..];
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = authority.rsplit('@').next().unwrap_or(authority);
+        let host = host.split(':').next().unwrap_or(host);
+        (Some(host.to_string()), path.to_string())
+    } else if let Some((before, after)) = url.split_once(':') {
+        // scp-like `[user@]host:owner/.../name`; but a `:` inside something
+        // that already looks like a path means it is a bare local path.
+        if before.contains('/') {
+            (None, url.to_string())
+        } else {
+            let host = before.rsplit('@').next().unwrap_or(before);
+            (Some(host.to_string()), after.to_string())
+        }
+    } else {
+        (None, url.to_string())
+    };
+
+    // Normalize: drop surrounding slashes and a trailing `.git`.
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+
+    let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let name = segments.pop()?.to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    // Bare local paths carry no host, and per `RepoInfo`'s contract shouldn't
+    // carry a synthetic owner either: `/srv/git/repo.git` is just a name, not
+    // a `srv/git`-owned repo.
+    let owner = if host.is_none() || segments.is_empty() {
+        None
+    } else {
+        Some(segments.join("/"))
+    };
+
+    Some(RepoInfo { host, owner, name })
+}
@@ -1,7 +1,19 @@
+pub mod alerts;
+pub mod cli;
 pub mod claude_logs;
+pub mod config;
+pub mod db;
+pub mod export;
 pub mod git_utils;
+pub mod logging;
+pub mod metrics_server;
+pub mod monitor;
+pub mod pricing;
+pub mod process;
 pub mod timeline_monitor;
 pub mod timeline_ui;
+pub mod ui;
+pub mod utils;
 
 pub use claude_logs::*;
 pub use git_utils::*;
@@ -0,0 +1,44 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+/// Destination for diagnostic output. `None` (the default) means stderr.
+static LOG_FILE: OnceLock<Mutex<Option<std::fs::File>>> = OnceLock::new();
+
+/// Redirect diagnostic output to `path`, or keep it on stderr when `None`.
+///
+/// Called once at startup so TUI users can keep `eprintln!`-style diagnostics
+/// out of the alternate screen by pointing `--log-file` at a file.
+pub fn set_log_file(path: Option<PathBuf>) {
+    let file = path.and_then(|p| {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&p)
+            .map_err(|e| eprintln!("Failed to open log file {}: {}", p.display(), e))
+            .ok()
+    });
+    let _ = LOG_FILE.set(Mutex::new(file));
+}
+
+/// Emit a diagnostic line to the configured log file, or stderr by default.
+pub fn diag(msg: &str) {
+    if let Some(lock) = LOG_FILE.get() {
+        if let Ok(mut guard) = lock.lock() {
+            if let Some(file) = guard.as_mut() {
+                let _ = writeln!(file, "{}", msg);
+                return;
+            }
+        }
+    }
+    eprintln!("{}", msg);
+}
+
+/// Write a formatted diagnostic line to the configured log file or stderr.
+#[macro_export]
+macro_rules! diag {
+    ($($arg:tt)*) => {
+        $crate::logging::diag(&format!($($arg)*))
+    };
+}
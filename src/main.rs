@@ -1,6 +1,109 @@
-use ccmonitor::{claude_logs::load_sessions_in_timerange, timeline_monitor::TimelineMonitor};
-use clap::Parser;
+use ccmonitor::{
+    claude_logs::{
+        aggregate_parent_projects, load_sessions_in_timerange, tail_sessions, ParentProjectTotals,
+        SessionTimeline,
+    },
+    export::{export_timelines, ExportFormat},
+    timeline_monitor::TimelineMonitor,
+};
+use clap::{Parser, ValueEnum};
 use chrono::{Duration, Local};
+use serde::Serialize;
+use tokio_stream::StreamExt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text (default)
+    Text,
+    /// JSON: timelines plus summary statistics
+    Json,
+    /// CSV: one row per project
+    Csv,
+}
+
+/// Summary statistics computed over a set of timelines.
+#[derive(Serialize)]
+struct Summary {
+    total_projects: usize,
+    total_events: usize,
+    average_duration_minutes: f64,
+    most_active_project: Option<String>,
+}
+
+impl Summary {
+    fn compute(timelines: &[SessionTimeline]) -> Self {
+        let total_projects = timelines.len();
+        let total_events = timelines.iter().map(|t| t.events.len()).sum();
+        let average_duration_minutes = if total_projects == 0 {
+            0.0
+        } else {
+            timelines
+                .iter()
+                .map(|t| t.active_duration_minutes as f64)
+                .sum::<f64>()
+                / total_projects as f64
+        };
+        let most_active_project = timelines
+            .iter()
+            .max_by_key(|t| t.events.len())
+            .map(|t| t.project_name.clone());
+
+        Self {
+            total_projects,
+            total_events,
+            average_duration_minutes,
+            most_active_project,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    timelines: &'a [SessionTimeline],
+    summary: Summary,
+    /// Aggregate token/duration totals for sessions rolled up under an
+    /// enclosing repository (worktrees/submodules); individual timelines
+    /// above retain their own per-`project_name` breakdown.
+    parent_groups: Vec<ParentProjectTotals>,
+}
+
+/// Escape a field for CSV output, quoting when it contains a comma or quote.
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print_json(timelines: &[SessionTimeline]) -> Result<(), Box<dyn std::error::Error>> {
+    let report = Report {
+        timelines,
+        summary: Summary::compute(timelines),
+        parent_groups: aggregate_parent_projects(timelines),
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+fn print_csv(timelines: &[SessionTimeline]) {
+    println!(
+        "project_name,parent_project,events,input_tokens,output_tokens,active_duration_minutes,start_time,end_time"
+    );
+    for t in timelines {
+        println!(
+            "{},{},{},{},{},{},{},{}",
+            csv_field(&t.project_name),
+            csv_field(t.parent_project.as_deref().unwrap_or("")),
+            t.events.len(),
+            t.total_input_tokens,
+            t.total_output_tokens,
+            t.active_duration_minutes,
+            t.start_time.to_rfc3339(),
+            t.end_time.to_rfc3339(),
+        );
+    }
+}
 
 fn format_number(num: u32) -> String {
     // Simple number formatting with commas
@@ -60,12 +163,66 @@ struct Args {
     /// Force TUI mode (may fail in some environments)
     #[arg(long)]
     tui: bool,
+
+    /// Redirect diagnostics (session loading, errors, panic backtrace) to a file
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<std::path::PathBuf>,
+
+    /// Continuously re-scan logs and refresh the TUI (live monitor mode)
+    #[arg(long)]
+    watch: bool,
+
+    /// Seconds between re-scans in watch mode
+    #[arg(long, default_value = "5")]
+    interval: u64,
+
+    /// Output format for simple mode
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+
+    /// Export timelines for an external visualizer instead of printing a
+    /// report (`json` for a flat array, `trace` for chrome://tracing/Perfetto)
+    #[arg(long, value_enum)]
+    export: Option<ExportFormat>,
+
+    /// Stream newly appended session events to stdout as they're written,
+    /// instead of printing a report. Runs until interrupted.
+    #[arg(long)]
+    tail: bool,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
+    // Route diagnostics to a file when requested (keeps stderr out of the TUI).
+    ccmonitor::logging::set_log_file(args.log_file.clone());
+
+    // `--tail` streams live events instead of running the report/TUI flow.
+    if args.tail {
+        let mut events = tail_sessions();
+        while let Some(event) = events.next().await {
+            println!("{}", serde_json::to_string(&event)?);
+        }
+        return Ok(());
+    }
+
+    // `--export` is a one-shot batch operation independent of simple/TUI mode.
+    if let Some(export_format) = args.export {
+        let now = Local::now();
+        let start_time = now - Duration::days(args.days);
+
+        let timelines = load_sessions_in_timerange(
+            start_time,
+            now,
+            args.project.as_deref(),
+            args.threads,
+        )?;
+
+        println!("{}", export_timelines(&timelines, export_format)?);
+        return Ok(());
+    }
+
     // Default to simple mode unless TUI is explicitly requested
     let use_simple = args.simple || (!args.tui && is_wsl_or_problematic_terminal());
     
@@ -75,9 +232,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let end_time = now;
         let start_time = end_time - Duration::days(args.days);
 
-        eprintln!("Loading Claude sessions from the last {} days...", args.days);
+        ccmonitor::diag!("Loading Claude sessions from the last {} days...", args.days);
         if let Some(ref project) = args.project {
-            eprintln!("Filtering by project: {}", project);
+            ccmonitor::diag!("Filtering by project: {}", project);
         }
 
         let timelines = load_sessions_in_timerange(
@@ -87,6 +244,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             args.threads,
         )?;
 
+        // Machine-readable formats emit regardless of emptiness, then exit.
+        match args.format {
+            OutputFormat::Json => {
+                print_json(&timelines)?;
+                return Ok(());
+            }
+            OutputFormat::Csv => {
+                print_csv(&timelines);
+                return Ok(());
+            }
+            OutputFormat::Text => {}
+        }
+
         if timelines.is_empty() {
             println!("No Claude sessions found in the specified time range.");
             return Ok(());
@@ -100,6 +270,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
         println!("Projects found: {}\n", timelines.len());
 
+        let pricing = ccmonitor::pricing::PricingTable::load().unwrap_or_default();
+
         for timeline in &timelines {
             let project_display = if let Some(ref _parent) = timeline.parent_project {
                 format!(" └─{}", timeline.project_name)
@@ -124,7 +296,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("  Input tokens: {}", input_tokens);
             println!("  Output tokens: {}", output_tokens);
             println!("  Duration: {}m", timeline.active_duration_minutes);
-            println!("  Time: {} - {}", 
+            println!("  Cost: {}", pricing.estimate_timeline(timeline).display());
+            println!("  Time: {} - {}",
                 timeline.start_time.format("%H:%M"), 
                 timeline.end_time.format("%H:%M")
             );
@@ -145,20 +318,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("  • Total Projects: {}", total_projects);
             println!("  • Total Events: {}", total_events);
             println!("  • Average Duration: {:.1} minutes", avg_duration);
-            println!("  • Most Active Project: {} ({} events)", 
+            println!("  • Most Active Project: {} ({} events)",
                 most_active.project_name, most_active.events.len());
+            println!("  • Estimated Cost: {}", pricing.estimate_total(&timelines).display());
         }
     } else {
         // TUI mode
-        let monitor = TimelineMonitor::new(args.days, args.project, args.threads);
+        let monitor = TimelineMonitor::with_watch(
+            args.days,
+            args.project,
+            args.threads,
+            args.watch,
+            args.interval,
+        );
         
         match monitor.run().await {
             Ok(()) => {
-                eprintln!("👋 Exiting.");
+                ccmonitor::diag!("👋 Exiting.");
             }
             Err(e) => {
-                eprintln!("❌ TUI Error: {}", e);
-                eprintln!("Try running with --simple flag for text output");
+                ccmonitor::diag!("❌ TUI Error: {}", e);
+                ccmonitor::diag!("Try running with --simple flag for text output");
                 std::process::exit(1);
             }
         }
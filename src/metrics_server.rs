@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::claude_logs::SessionTimeline;
+use crate::db::ProcessStats;
+use crate::process::ProcessInfo;
+
+/// The slice of state exposed over the metrics endpoint. The collector refreshes
+/// it in place; the server renders whatever is current on each scrape, so a
+/// scrape never blocks sampling for longer than it takes to clone the snapshot.
+#[derive(Default, Clone)]
+pub struct MetricsSnapshot {
+    pub processes: Vec<ProcessInfo>,
+    pub stats: ProcessStats,
+    pub timelines: Vec<SessionTimeline>,
+}
+
+/// Serve the Prometheus `/metrics` endpoint until the task is dropped.
+///
+/// This is a deliberately tiny HTTP/1.1 server — the exposition format is the
+/// only thing we need and pulling in a web framework for one read-only route
+/// would be out of step with the rest of the crate.
+pub async fn serve(addr: SocketAddr, shared: Arc<Mutex<MetricsSnapshot>>) -> Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics server to {}", addr))?;
+
+    loop {
+        let (mut stream, _peer) = listener.accept().await?;
+        let shared = Arc::clone(&shared);
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(&mut stream, &shared).await {
+                tracing::error!("metrics connection error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    stream: &mut tokio::net::TcpStream,
+    shared: &Arc<Mutex<MetricsSnapshot>>,
+) -> Result<()> {
+    // We only ever serve one short route, so reading the first request line is
+    // enough to decide how to respond.
+    let mut buf = [0u8; 1024];
+    let n = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let (status, body) = if path == "/metrics" {
+        let snapshot = shared.lock().unwrap().clone();
+        ("200 OK", render_metrics(&snapshot))
+    } else {
+        ("404 Not Found", String::from("not found\n"))
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {len}\r\nConnection: close\r\n\r\n{body}",
+        len = body.len(),
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+/// Render the snapshot as a Prometheus text exposition document.
+pub fn render_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP ccstat_process_cpu_percent CPU usage of a Claude process.\n");
+    out.push_str("# TYPE ccstat_process_cpu_percent gauge\n");
+    for p in &snapshot.processes {
+        let _ = writeln!(
+            out,
+            "ccstat_process_cpu_percent{{pid=\"{}\",name=\"{}\"}} {}",
+            p.pid,
+            escape_label(&p.name),
+            p.cpu_percent,
+        );
+    }
+
+    out.push_str("# HELP ccstat_process_memory_mb Resident memory of a Claude process in MB.\n");
+    out.push_str("# TYPE ccstat_process_memory_mb gauge\n");
+    for p in &snapshot.processes {
+        let _ = writeln!(
+            out,
+            "ccstat_process_memory_mb{{pid=\"{}\",name=\"{}\"}} {}",
+            p.pid,
+            escape_label(&p.name),
+            p.memory_mb,
+        );
+    }
+
+    out.push_str("# HELP ccstat_process_count Number of distinct Claude processes observed.\n");
+    out.push_str("# TYPE ccstat_process_count gauge\n");
+    let _ = writeln!(out, "ccstat_process_count {}", snapshot.stats.process_count);
+
+    out.push_str("# HELP ccstat_session_input_tokens_total Input tokens consumed by a session.\n");
+    out.push_str("# TYPE ccstat_session_input_tokens_total counter\n");
+    for t in &snapshot.timelines {
+        let _ = writeln!(
+            out,
+            "ccstat_session_input_tokens_total{{project=\"{}\",session_id=\"{}\"}} {}",
+            escape_label(&t.project_name),
+            escape_label(&t.session_id),
+            t.total_input_tokens,
+        );
+    }
+
+    out.push_str("# HELP ccstat_session_output_tokens_total Output tokens produced for a session.\n");
+    out.push_str("# TYPE ccstat_session_output_tokens_total counter\n");
+    for t in &snapshot.timelines {
+        let _ = writeln!(
+            out,
+            "ccstat_session_output_tokens_total{{project=\"{}\",session_id=\"{}\"}} {}",
+            escape_label(&t.project_name),
+            escape_label(&t.session_id),
+            t.total_output_tokens,
+        );
+    }
+
+    out
+}
+
+/// Escape a label value per the Prometheus exposition format: backslash, double
+/// quote and newline are the only characters that need escaping.
+fn escape_label(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
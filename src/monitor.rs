@@ -1,75 +1,257 @@
 use anyhow::Result;
-use chrono::Local;
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tokio::time;
 
 use crate::{
-    db::Database,
-    process::{read_claude_tasks, ProcessMonitor},
-    ui::UI,
+    alerts::{Alert, AlertKind, CpuMatcher, MemoryMatcher, StateTracker},
+    cli::Cli,
+    config::{parse_color, Config},
+    db::{Database, ProcessStats, RetentionPolicy},
+    metrics_server::{self, MetricsSnapshot},
+    process::{read_claude_tasks, ClaudeTask, ProcessHistory, ProcessInfo, ProcessMonitor},
+    ui::{Theme, UI},
 };
 
 pub struct Monitor {
     process_monitor: ProcessMonitor,
     database: Database,
     ui: UI,
+    /// Data-collection interval, from config.
+    refresh_interval_ms: u64,
+    /// Minutes of history shown in the charts, from config.
+    history_minutes: u32,
+    /// Caps enforced on stored metrics and sessions when the collector exits.
+    retention_policy: RetentionPolicy,
+    /// Address to expose the Prometheus `/metrics` endpoint on, if enabled.
+    metrics_addr: Option<SocketAddr>,
+    /// CPU percent a process must sustain to raise an alert, from config.
+    cpu_alert_percent: f32,
+    /// Resident memory (MB) a process must sustain to raise an alert, from config.
+    memory_alert_mb: f64,
+    /// How long a process must stay over a threshold before the alert fires.
+    alert_sustain_secs: u64,
+    /// Query expression (e.g. `cpu >= 5`) the process table is restricted to,
+    /// from `--filter`; shows every process when unset.
+    filter: Option<String>,
+}
+
+/// The most recent sample published by the collector thread and consumed by the
+/// redraw loop. Cloned on each frame so the UI never holds the lock while drawing.
+#[derive(Default, Clone)]
+struct Snapshot {
+    processes: Vec<ProcessInfo>,
+    stats: ProcessStats,
+    cpu_history: Vec<(DateTime<Local>, f32)>,
+    memory_history: Vec<(DateTime<Local>, f64)>,
+    tasks: Vec<ClaudeTask>,
+    /// Per-PID CPU/memory trend sparklines, keyed by PID, for the process table.
+    histories: HashMap<u32, ProcessHistory>,
 }
 
 impl Monitor {
     pub fn new() -> Result<Self> {
+        Self::with_settings(false, Config::default(), None, None)
+    }
+
+    pub fn with_basic(basic: bool) -> Result<Self> {
+        Self::with_settings(basic, Config::default(), None, None)
+    }
+
+    /// Build a monitor from parsed CLI args, loading the TOML config and letting
+    /// explicit flags take precedence over persisted values.
+    pub fn from_cli(cli: &Cli) -> Result<Self> {
+        let config = Config::load(cli.config.clone())?;
+        // `--basic`, `--metrics-addr` and the retention caps are flags with no
+        // config key (besides `max_age_days`, which falls back to the config
+        // file); flags always win.
+        Self::with_settings(cli.basic, config, cli.metrics_addr, Some(cli))
+    }
+
+    fn with_settings(
+        basic: bool,
+        config: Config,
+        metrics_addr: Option<SocketAddr>,
+        cli: Option<&Cli>,
+    ) -> Result<Self> {
+        let defaults = Theme::default();
+        let theme = Theme {
+            primary: parse_color(&config.colors.primary, defaults.primary),
+            accent: parse_color(&config.colors.accent, defaults.accent),
+            alert: parse_color(&config.colors.alert, defaults.alert),
+        };
+
+        let retention_policy = RetentionPolicy {
+            max_total_bytes: cli.and_then(|c| c.max_db_bytes),
+            max_sessions_per_project: cli.and_then(|c| c.max_sessions_per_project),
+            max_events_per_session: cli.and_then(|c| c.max_events_per_session),
+            max_age_days: cli
+                .and_then(|c| c.max_age_days)
+                .unwrap_or(config.data_retention_days),
+        };
+
         Ok(Self {
             process_monitor: ProcessMonitor::new(),
             database: Database::new()?,
-            ui: UI::new()?,
+            ui: UI::with_settings(basic, theme, config.default_tab_index())?,
+            refresh_interval_ms: config.refresh_interval_ms,
+            history_minutes: config.history_minutes,
+            retention_policy,
+            metrics_addr,
+            cpu_alert_percent: config.cpu_alert_percent,
+            memory_alert_mb: config.memory_alert_mb,
+            alert_sustain_secs: config.alert_sustain_secs,
+            filter: cli.and_then(|c| c.filter.clone()),
         })
     }
 
-    pub async fn run(&mut self) -> Result<()> {
-        let mut interval = time::interval(Duration::from_secs(1));
+    pub async fn run(mut self) -> Result<()> {
+        // Sampling + DB work runs on a background thread at the (slow) refresh
+        // interval and publishes the latest `Snapshot`; the redraw/input loop
+        // below ticks far faster so keypresses stay responsive and we don't
+        // re-query the database on every frame.
+        let latest: Arc<Mutex<Snapshot>> = Arc::new(Mutex::new(Snapshot::default()));
+        let metrics_snapshot: Arc<Mutex<MetricsSnapshot>> =
+            Arc::new(Mutex::new(MetricsSnapshot::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let (kill_tx, kill_rx) = mpsc::channel::<u32>();
 
-        loop {
-            interval.tick().await;
+        let interval_ms = self.refresh_interval_ms;
+        let history_minutes = self.history_minutes;
+        let retention_policy = self.retention_policy;
+        let filter = self.filter;
+        let mut process_monitor = self.process_monitor;
+        let mut database = self.database;
+        let collector_slot = Arc::clone(&latest);
+        let collector_stop = Arc::clone(&stop);
+        let collector_metrics_slot = Arc::clone(&metrics_snapshot);
+        let metrics_enabled = self.metrics_addr.is_some();
 
-            // Refresh process information
-            self.process_monitor.refresh();
+        let sustain = Duration::from_secs(self.alert_sustain_secs);
+        let mut cpu_tracker = StateTracker::new(CpuMatcher { threshold: self.cpu_alert_percent }, sustain);
+        let mut memory_tracker =
+            StateTracker::new(MemoryMatcher { threshold_mb: self.memory_alert_mb }, sustain);
+
+        // Only serve `/metrics` when the user opted in; otherwise skip the bind
+        // and the per-tick session reload it would otherwise require.
+        if let Some(addr) = self.metrics_addr {
+            let serve_slot = Arc::clone(&metrics_snapshot);
+            tokio::spawn(async move {
+                if let Err(e) = metrics_server::serve(addr, serve_slot).await {
+                    tracing::error!("Metrics server on {} stopped: {}", addr, e);
+                }
+            });
+        }
 
-            // Get current processes
-            let processes = self.process_monitor.get_claude_processes();
+        let collector = std::thread::spawn(move || {
+            while !collector_stop.load(Ordering::Relaxed) {
+                process_monitor.refresh();
+                let processes = match &filter {
+                    Some(expr) => process_monitor.filter_by_query(expr).unwrap_or_else(|e| {
+                        tracing::error!("Invalid --filter expression {:?}: {}", expr, e);
+                        process_monitor.get_claude_processes()
+                    }),
+                    None => process_monitor.get_claude_processes(),
+                };
+                let histories: HashMap<u32, ProcessHistory> = processes
+                    .iter()
+                    .map(|p| (p.pid, process_monitor.history_sparklines(p.pid)))
+                    .collect();
 
-            // Save to database
-            for process in &processes {
-                if let Err(e) = self.database.insert_process_metrics(process) {
-                    tracing::error!("Failed to insert process metrics: {}", e);
+                for process in &processes {
+                    if let Err(e) = database.insert_process_metrics(process) {
+                        tracing::error!("Failed to insert process metrics: {}", e);
+                    }
                 }
-            }
 
-            // Get statistics
-            let stats = self.database.get_process_stats(None, None)?;
+                for alert in cpu_tracker.poll(&processes).into_iter().chain(memory_tracker.poll(&processes)) {
+                    log_alert(alert);
+                }
 
-            // Get history for charts (last 5 minutes)
-            let cpu_history = self.database.get_cpu_history(5)?;
-            let memory_history = self.database.get_memory_history(5)?;
+                let stats = database.get_process_stats(None, None).unwrap_or_default();
+                let cpu_history = database.get_cpu_history(history_minutes).unwrap_or_default();
+                let memory_history =
+                    database.get_memory_history(history_minutes).unwrap_or_default();
+                let tasks = read_claude_tasks().unwrap_or_default();
 
-            // Get Claude tasks
-            let tasks = read_claude_tasks().unwrap_or_default();
+                if metrics_enabled {
+                    if let Err(e) = database.ingest_claude_logs() {
+                        tracing::error!("Failed to ingest Claude session logs: {}", e);
+                    }
 
-            // Draw UI
+                    if let Ok(mut slot) = collector_metrics_slot.lock() {
+                        let now = Local::now();
+                        let timelines = database
+                            .load_sessions_in_timerange(now - chrono::Duration::hours(24), now, None, false)
+                            .unwrap_or_default();
+                        *slot = MetricsSnapshot {
+                            processes: processes.clone(),
+                            stats: stats.clone(),
+                            timelines,
+                        };
+                    }
+                }
+
+                if let Ok(mut slot) = collector_slot.lock() {
+                    *slot = Snapshot {
+                        processes,
+                        stats,
+                        cpu_history,
+                        memory_history,
+                        tasks,
+                        histories,
+                    };
+                }
+
+                // Honor any kill requests the UI queued between samples.
+                while let Ok(pid) = kill_rx.try_recv() {
+                    if !process_monitor.kill(pid) {
+                        tracing::error!("Failed to kill process {}", pid);
+                    }
+                }
+
+                std::thread::sleep(Duration::from_millis(interval_ms));
+            }
+
+            // Enforce retention caps before the collector exits.
+            if let Err(e) = database.enforce_retention(&retention_policy) {
+                tracing::error!("Failed to enforce retention policy: {}", e);
+            }
+        });
+
+        // Fast redraw/input loop (~5 FPS) consuming the most recent snapshot.
+        let mut ticker = time::interval(Duration::from_millis(200));
+        loop {
+            ticker.tick().await;
+
+            let snapshot = latest.lock().unwrap().clone();
             self.ui.draw(
-                &processes,
-                &stats,
-                &cpu_history,
-                &memory_history,
-                &tasks,
+                &snapshot.processes,
+                &snapshot.stats,
+                &snapshot.cpu_history,
+                &snapshot.memory_history,
+                &snapshot.tasks,
+                &snapshot.histories,
             )?;
 
-            // Handle user input
             if self.ui.handle_input()? {
                 break;
             }
+
+            // Forward a confirmed kill request to the collector thread.
+            if let Some(pid) = self.ui.take_kill_request() {
+                let _ = kill_tx.send(pid);
+            }
         }
 
-        // Clean up old data before exiting
-        self.database.cleanup_old_data(7)?;
+        // Signal the collector to stop and wait for it to flush cleanup.
+        stop.store(true, Ordering::Relaxed);
+        let _ = collector.join();
 
         Ok(())
     }
@@ -78,14 +260,24 @@ impl Monitor {
         // Refresh process information
         self.process_monitor.refresh();
 
-        // Get current processes
-        let processes = self.process_monitor.get_claude_processes();
+        // Get current processes, restricted to `--filter` when given
+        let processes = match &self.filter {
+            Some(expr) => self.process_monitor.filter_by_query(expr).unwrap_or_else(|e| {
+                tracing::error!("Invalid --filter expression {:?}: {}", expr, e);
+                self.process_monitor.get_claude_processes()
+            }),
+            None => self.process_monitor.get_claude_processes(),
+        };
+        let histories: HashMap<u32, ProcessHistory> = processes
+            .iter()
+            .map(|p| (p.pid, self.process_monitor.history_sparklines(p.pid)))
+            .collect();
 
         // Get statistics for the last hour
         let stats = self.database.get_process_stats(None, None)?;
 
         // Show summary UI
-        self.ui.show_summary(&stats, &processes)?;
+        self.ui.show_summary(&stats, &processes, &histories)?;
 
         // Wait for user input to exit
         loop {
@@ -97,4 +289,12 @@ impl Monitor {
 
         Ok(())
     }
+}
+
+/// Log a process alert transition at the severity matching its kind.
+fn log_alert(alert: Alert) {
+    match alert.kind {
+        AlertKind::Crossed => tracing::warn!(pid = alert.pid, "process sustained alert threshold"),
+        AlertKind::Cleared => tracing::info!(pid = alert.pid, "process alert cleared"),
+    }
 }
\ No newline at end of file
@@ -0,0 +1,147 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::claude_logs::SessionTimeline;
+use crate::utils::get_data_dir;
+
+/// Per-million-token prices for a single model, in USD.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct ModelPrice {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+/// A table mapping model names to their token prices.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PricingTable {
+    #[serde(flatten)]
+    models: HashMap<String, ModelPrice>,
+}
+
+/// Estimated cost of a session. `unknown` is set when at least one charged
+/// event used a model that is absent from the table.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct CostEstimate {
+    pub usd: f64,
+    pub unknown: bool,
+}
+
+impl Default for PricingTable {
+    /// Built-in defaults covering the common Claude models, in USD per million
+    /// tokens. Overridden by `pricing.toml` when present.
+    fn default() -> Self {
+        let mut models = HashMap::new();
+        models.insert(
+            "claude-3-5-haiku".to_string(),
+            ModelPrice { input_per_million: 0.80, output_per_million: 4.00 },
+        );
+        models.insert(
+            "claude-3-5-sonnet".to_string(),
+            ModelPrice { input_per_million: 3.00, output_per_million: 15.00 },
+        );
+        models.insert(
+            "claude-sonnet-4".to_string(),
+            ModelPrice { input_per_million: 3.00, output_per_million: 15.00 },
+        );
+        models.insert(
+            "claude-opus-4".to_string(),
+            ModelPrice { input_per_million: 15.00, output_per_million: 75.00 },
+        );
+        Self { models }
+    }
+}
+
+impl PricingTable {
+    /// Load the pricing table from `pricing.toml` under [`get_data_dir`],
+    /// creating it with the built-in defaults if it does not exist.
+    pub fn load() -> Result<Self> {
+        let path = Self::config_path()?;
+
+        if !path.exists() {
+            let defaults = Self::default();
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            if let Ok(serialized) = toml::to_string_pretty(&defaults) {
+                std::fs::write(&path, serialized).ok();
+            }
+            return Ok(defaults);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read pricing file: {}", path.display()))?;
+        let table: PricingTable = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse pricing file: {}", path.display()))?;
+        Ok(table)
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        let mut path = get_data_dir()?;
+        path.push("pricing.toml");
+        Ok(path)
+    }
+
+    /// Find the price for a model, matching by exact name first and then by the
+    /// longest table key that the model name starts with (to absorb version
+    /// date suffixes such as `claude-sonnet-4-20250514`).
+    pub fn price_for(&self, model: &str) -> Option<ModelPrice> {
+        if let Some(price) = self.models.get(model) {
+            return Some(*price);
+        }
+        self.models
+            .iter()
+            .filter(|(key, _)| model.starts_with(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, price)| *price)
+    }
+
+    /// Estimate the USD cost of a timeline, summing per-event input/output
+    /// charges by model. Events with charged tokens but an unrecognized model
+    /// flag the estimate as `unknown` rather than being counted as free.
+    pub fn estimate_timeline(&self, timeline: &SessionTimeline) -> CostEstimate {
+        let mut usd = 0.0;
+        let mut unknown = false;
+
+        for event in &timeline.events {
+            if event.input_tokens == 0 && event.output_tokens == 0 {
+                continue;
+            }
+            match event.model.as_deref().and_then(|m| self.price_for(m)) {
+                Some(price) => {
+                    usd += event.input_tokens as f64 / 1_000_000.0 * price.input_per_million;
+                    usd += event.output_tokens as f64 / 1_000_000.0 * price.output_per_million;
+                }
+                None => unknown = true,
+            }
+        }
+
+        CostEstimate { usd, unknown }
+    }
+
+    /// Estimate the total cost across many timelines.
+    pub fn estimate_total(&self, timelines: &[SessionTimeline]) -> CostEstimate {
+        let mut usd = 0.0;
+        let mut unknown = false;
+        for timeline in timelines {
+            let estimate = self.estimate_timeline(timeline);
+            usd += estimate.usd;
+            unknown |= estimate.unknown;
+        }
+        CostEstimate { usd, unknown }
+    }
+}
+
+impl CostEstimate {
+    /// Format as a dollar figure, appending a marker when any model was unknown.
+    pub fn display(&self) -> String {
+        if self.usd == 0.0 && self.unknown {
+            "cost unknown".to_string()
+        } else if self.unknown {
+            format!("${:.2} (+ unknown)", self.usd)
+        } else {
+            format!("${:.2}", self.usd)
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use anyhow::Result;
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Duration, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use sysinfo::{Pid, Process, ProcessRefreshKind, System};
 
+/// Number of samples retained per process for the live sparklines.
+const WINDOW_SIZE: usize = 32;
+
+/// Block-ramp characters used to draw a sparkline, from empty to full.
+const SPARK_RAMP: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessInfo {
     pub pid: u32,
@@ -16,18 +22,36 @@ pub struct ProcessInfo {
     pub cmd: Vec<String>,
 }
 
+/// Width (in characters) of the CPU/memory trend sparklines in the process table.
+pub const SPARKLINE_WIDTH: usize = 10;
+
+/// Rendered CPU and memory trend sparklines for one process, built from its
+/// recent history.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessHistory {
+    pub cpu_sparkline: String,
+    pub mem_sparkline: String,
+}
+
 pub struct ProcessMonitor {
     system: System,
+    /// Bounded per-PID CPU-percent history for the sparklines.
+    cpu_history: HashMap<u32, VecDeque<f32>>,
+    /// Bounded per-PID memory (MB) history for the sparklines.
+    memory_history: HashMap<u32, VecDeque<f32>>,
 }
 
 impl ProcessMonitor {
     pub fn new() -> Self {
         Self {
             system: System::new_all(),
+            cpu_history: HashMap::new(),
+            memory_history: HashMap::new(),
         }
     }
 
-    /// Refresh process information
+    /// Refresh process information and append one sample per live Claude process
+    /// to the bounded history ring buffers.
     pub fn refresh(&mut self) {
         self.system.refresh_processes_specifics(
             ProcessRefreshKind::new()
@@ -35,6 +59,46 @@ impl ProcessMonitor {
                 .with_memory()
                 .with_cmd(sysinfo::UpdateKind::OnlyIfNotSet),
         );
+
+        let processes = self.get_claude_processes();
+        let live: std::collections::HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+
+        for process in &processes {
+            push_sample(self.cpu_history.entry(process.pid).or_default(), process.cpu_percent);
+            push_sample(
+                self.memory_history.entry(process.pid).or_default(),
+                process.memory_mb as f32,
+            );
+        }
+
+        // Drop history for processes that have exited so the maps stay bounded.
+        self.cpu_history.retain(|pid, _| live.contains(pid));
+        self.memory_history.retain(|pid, _| live.contains(pid));
+    }
+
+    /// Recent CPU-percent samples for a process, oldest first.
+    pub fn cpu_history(&self, pid: u32) -> Option<&VecDeque<f32>> {
+        self.cpu_history.get(&pid)
+    }
+
+    /// Recent memory (MB) samples for a process, oldest first.
+    pub fn memory_history(&self, pid: u32) -> Option<&VecDeque<f32>> {
+        self.memory_history.get(&pid)
+    }
+
+    /// Render the CPU and memory trend sparklines for `pid` from its recent
+    /// history, blank when the process has no history yet (it just appeared).
+    pub fn history_sparklines(&self, pid: u32) -> ProcessHistory {
+        ProcessHistory {
+            cpu_sparkline: self
+                .cpu_history(pid)
+                .map(|h| Self::render_sparkline(h, SPARKLINE_WIDTH))
+                .unwrap_or_else(|| " ".repeat(SPARKLINE_WIDTH)),
+            mem_sparkline: self
+                .memory_history(pid)
+                .map(|h| Self::render_sparkline(h, SPARKLINE_WIDTH))
+                .unwrap_or_else(|| " ".repeat(SPARKLINE_WIDTH)),
+        }
     }
 
     /// Get all Claude Code processes
@@ -100,15 +164,250 @@ impl ProcessMonitor {
             .into_iter()
             .find(|p| p.pid == pid)
     }
+
+    /// Render `samples` as a block-ramp sparkline `width` characters wide,
+    /// showing the most recent samples normalized against the window maximum.
+    ///
+    /// An empty history renders a blank bar, and the maximum is guarded from
+    /// zero so a flat-zero series does not divide by zero.
+    pub fn render_sparkline(samples: &VecDeque<f32>, width: usize) -> String {
+        if samples.is_empty() || width == 0 {
+            return " ".repeat(width);
+        }
+
+        let recent: Vec<f32> = samples.iter().rev().take(width).rev().copied().collect();
+        let max = recent.iter().cloned().fold(0.0_f32, f32::max).max(f32::EPSILON);
+
+        let mut bar = String::with_capacity(width);
+        // Left-pad so the trend is right-aligned when there are fewer samples
+        // than the available width.
+        for _ in recent.len()..width {
+            bar.push(' ');
+        }
+        for &sample in &recent {
+            let level = ((sample / max) * (SPARK_RAMP.len() - 1) as f32).round() as usize;
+            bar.push(SPARK_RAMP[level.min(SPARK_RAMP.len() - 1)]);
+        }
+        bar
+    }
+
+    /// Return the Claude processes whose runtime (in seconds) satisfies
+    /// `predicate`.
+    pub fn filter_by_runtime<F>(&self, predicate: F) -> Vec<ProcessInfo>
+    where
+        F: Fn(u64) -> bool,
+    {
+        self.get_claude_processes()
+            .into_iter()
+            .filter(|p| predicate(p.runtime_seconds))
+            .collect()
+    }
+
+    /// Return the Claude processes matching a query expression such as
+    /// `runtime > 30m`, `cpu >= 5`, or `mem > 200`.
+    pub fn filter_by_query(&self, expr: &str) -> Result<Vec<ProcessInfo>> {
+        let query = ProcessQuery::parse(expr)?;
+        Ok(self
+            .get_claude_processes()
+            .into_iter()
+            .filter(|p| query.matches(p))
+            .collect())
+    }
+
+    /// Send SIGTERM (or the platform equivalent) to a process by PID.
+    ///
+    /// Returns `true` when the signal was delivered. The process table must
+    /// have been refreshed recently enough to still know about `pid`.
+    pub fn kill(&self, pid: u32) -> bool {
+        if let Some(process) = self.system.process(Pid::from_u32(pid)) {
+            process.kill_with(sysinfo::Signal::Term).unwrap_or(false)
+        } else {
+            false
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A `ProcessInfo` field a query can filter on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryField {
+    Cpu,
+    Mem,
+    Runtime,
+}
+
+/// A comparison operator in a query expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueryOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+}
+
+/// A parsed `<field> <op> <value>` process filter, e.g. `runtime > 30m`.
+///
+/// `value` is normalized to the field's unit: percent for `cpu`, MB for `mem`,
+/// and seconds for `runtime` (durations accept `s`/`m`/`h`/`d` suffixes).
+pub struct ProcessQuery {
+    field: QueryField,
+    op: QueryOp,
+    value: f64,
+}
+
+impl ProcessQuery {
+    /// Parse an expression like `cpu >= 5` or `runtime > 30m`.
+    pub fn parse(expr: &str) -> Result<Self> {
+        // Match the longest operators first so `>=` isn't read as `>`.
+        let (field_str, op, value_str) = [">=", "<=", "==", ">", "<", "="]
+            .iter()
+            .find_map(|token| {
+                expr.split_once(token).map(|(lhs, rhs)| {
+                    let op = match *token {
+                        ">=" => QueryOp::Ge,
+                        "<=" => QueryOp::Le,
+                        "<" => QueryOp::Lt,
+                        ">" => QueryOp::Gt,
+                        _ => QueryOp::Eq,
+                    };
+                    (lhs.trim(), op, rhs.trim())
+                })
+            })
+            .ok_or_else(|| anyhow::anyhow!("no comparison operator in query: {expr:?}"))?;
+
+        let field = match field_str.to_lowercase().as_str() {
+            "cpu" => QueryField::Cpu,
+            "mem" | "memory" => QueryField::Mem,
+            "runtime" => QueryField::Runtime,
+            other => return Err(anyhow::anyhow!("unknown query field: {other:?}")),
+        };
+
+        let value = match field {
+            QueryField::Runtime => parse_duration_seconds(value_str)? as f64,
+            _ => value_str
+                .parse::<f64>()
+                .map_err(|_| anyhow::anyhow!("invalid number in query: {value_str:?}"))?,
+        };
+
+        Ok(Self { field, op, value })
+    }
+
+    /// Whether `process` satisfies this query.
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        let lhs = match self.field {
+            QueryField::Cpu => process.cpu_percent as f64,
+            QueryField::Mem => process.memory_mb,
+            QueryField::Runtime => process.runtime_seconds as f64,
+        };
+        match self.op {
+            QueryOp::Gt => lhs > self.value,
+            QueryOp::Ge => lhs >= self.value,
+            QueryOp::Lt => lhs < self.value,
+            QueryOp::Le => lhs <= self.value,
+            QueryOp::Eq => lhs == self.value,
+        }
+    }
+}
+
+/// Parse a duration like `30m`, `45s`, `2h`, `1d`, or a bare number of seconds.
+pub fn parse_duration_seconds(text: &str) -> Result<u64> {
+    let text = text.trim();
+    let (number, multiplier) = match text.chars().last() {
+        Some('s') => (&text[..text.len() - 1], 1),
+        Some('m') => (&text[..text.len() - 1], 60),
+        Some('h') => (&text[..text.len() - 1], 3600),
+        Some('d') => (&text[..text.len() - 1], 86_400),
+        _ => (text, 1),
+    };
+
+    number
+        .trim()
+        .parse::<u64>()
+        .map(|n| n * multiplier)
+        .map_err(|_| anyhow::anyhow!("invalid duration in query: {text:?}"))
+}
+
+/// Append a sample to a bounded ring buffer, evicting the oldest when full.
+fn push_sample(history: &mut VecDeque<f32>, sample: f32) {
+    if history.len() == WINDOW_SIZE {
+        history.pop_front();
+    }
+    history.push_back(sample);
+}
+
+/// Task importance, parsed from the `priority` field when present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Parse a case-insensitive priority label.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "low" => Some(Self::Low),
+            "medium" | "med" => Some(Self::Medium),
+            "high" => Some(Self::High),
+            _ => None,
+        }
+    }
+
+    /// Short label for display.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+
+    /// Ordering rank, highest priority first when sorting descending.
+    fn rank(self) -> u8 {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::High => 2,
+        }
+    }
+}
+
+/// A block of time logged against a task on a given day.
+#[derive(Debug, Clone)]
+pub struct TimeEntry {
+    pub logged_date: NaiveDate,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone)]
 pub struct ClaudeTask {
     pub id: String,
     pub name: String,
     pub status: String,
+    pub priority: Option<Priority>,
     pub created_at: DateTime<Local>,
     pub updated_at: DateTime<Local>,
+    pub time_entries: Vec<TimeEntry>,
+}
+
+impl ClaudeTask {
+    /// Total time logged across all entries.
+    pub fn total_logged(&self) -> Duration {
+        self.time_entries
+            .iter()
+            .fold(Duration::zero(), |acc, entry| acc + entry.duration)
+    }
+}
+
+/// Sort tasks by descending priority, then most-recent update first.
+pub fn sort_tasks(tasks: &mut [ClaudeTask]) {
+    tasks.sort_by(|a, b| {
+        let rank = |t: &ClaudeTask| t.priority.map_or(0, Priority::rank);
+        rank(b)
+            .cmp(&rank(a))
+            .then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
 }
 
 /// Read Claude tasks from ~/.claude.json
@@ -123,13 +422,13 @@ pub fn read_claude_tasks() -> Result<Vec<ClaudeTask>> {
     }
 
     let content = fs::read_to_string(&path)?;
-    
-    // Parse the JSON - actual structure may vary
-    // This is a placeholder implementation
+
+    // The on-disk structure varies; read the fields we understand and fall back
+    // gracefully on the rest.
     let json: serde_json::Value = serde_json::from_str(&content)?;
-    
+
     let mut tasks = Vec::new();
-    
+
     if let Some(task_array) = json.get("tasks").and_then(|v| v.as_array()) {
         for task in task_array {
             if let Some(task_obj) = task.as_object() {
@@ -146,8 +445,17 @@ pub fn read_claude_tasks() -> Result<Vec<ClaudeTask>> {
                         .and_then(|v| v.as_str())
                         .unwrap_or("unknown")
                         .to_string(),
-                    created_at: Local::now(), // Placeholder
-                    updated_at: Local::now(), // Placeholder
+                    priority: task_obj
+                        .get("priority")
+                        .and_then(|v| v.as_str())
+                        .and_then(Priority::parse),
+                    created_at: parse_timestamp(task_obj.get("created_at").or(task_obj.get("createdAt"))),
+                    updated_at: parse_timestamp(task_obj.get("updated_at").or(task_obj.get("updatedAt"))),
+                    time_entries: task_obj
+                        .get("time_entries")
+                        .and_then(|v| v.as_array())
+                        .map(|entries| entries.iter().filter_map(parse_time_entry).collect())
+                        .unwrap_or_default(),
                 };
                 tasks.push(task);
             }
@@ -155,4 +463,33 @@ pub fn read_claude_tasks() -> Result<Vec<ClaudeTask>> {
     }
 
     Ok(tasks)
+}
+
+/// Parse an RFC3339/ISO-8601 timestamp into local time, falling back to "now"
+/// when the field is missing or unparseable.
+fn parse_timestamp(value: Option<&serde_json::Value>) -> DateTime<Local> {
+    value
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Local))
+        .unwrap_or_else(Local::now)
+}
+
+/// Parse a single `{ date, duration_minutes }` time entry, skipping malformed ones.
+fn parse_time_entry(value: &serde_json::Value) -> Option<TimeEntry> {
+    let obj = value.as_object()?;
+    let date_str = obj
+        .get("date")
+        .or(obj.get("logged_date"))
+        .and_then(|v| v.as_str())?;
+    let logged_date = NaiveDate::parse_from_str(date_str, "%Y-%m-%d").ok()?;
+    let minutes = obj
+        .get("duration_minutes")
+        .or(obj.get("minutes"))
+        .and_then(|v| v.as_i64())?;
+
+    Some(TimeEntry {
+        logged_date,
+        duration: Duration::minutes(minutes),
+    })
 }
\ No newline at end of file
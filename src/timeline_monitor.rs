@@ -2,7 +2,8 @@ use crate::{claude_logs::load_sessions_in_timerange, timeline_ui::TimelineUI};
 use anyhow::Result;
 use chrono::{DateTime, Duration, Local};
 use crossterm::{
-    event::{self, Event, KeyCode},
+    cursor::Show,
+    event::{self, Event},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -16,14 +17,30 @@ pub struct TimelineMonitor {
     pub days: i64,
     pub project: Option<String>,
     pub threads: bool,
+    /// Re-scan logs and redraw on a timer instead of rendering a static snapshot.
+    pub watch: bool,
+    /// Seconds between re-scans in watch mode.
+    pub interval: u64,
 }
 
 impl TimelineMonitor {
     pub fn new(days: i64, project: Option<String>, threads: bool) -> Self {
+        Self::with_watch(days, project, threads, false, 5)
+    }
+
+    pub fn with_watch(
+        days: i64,
+        project: Option<String>,
+        threads: bool,
+        watch: bool,
+        interval: u64,
+    ) -> Self {
         Self {
             days,
             project,
             threads,
+            watch,
+            interval: interval.max(1),
         }
     }
 
@@ -33,10 +50,21 @@ impl TimelineMonitor {
         let end_time = now;
         let start_time = end_time - Duration::days(self.days);
 
+        // Install a panic hook that restores the terminal before the backtrace
+        // is printed, so a mid-render panic can't strand the user in raw mode
+        // with the alternate screen still active.
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = disable_raw_mode();
+            let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+            crate::logging::diag(&format!("{}", info));
+            default_hook(info);
+        }));
+
         // Load sessions
-        eprintln!("Loading Claude sessions from the last {} days...", self.days);
+        crate::diag!("Loading Claude sessions from the last {} days...", self.days);
         if let Some(ref project) = self.project {
-            eprintln!("Filtering by project: {}", project);
+            crate::diag!("Filtering by project: {}", project);
         }
 
         let timelines = load_sessions_in_timerange(
@@ -47,7 +75,7 @@ impl TimelineMonitor {
         )?;
 
         if timelines.is_empty() {
-            eprintln!("No Claude sessions found in the specified time range.");
+            crate::diag!("No Claude sessions found in the specified time range.");
             return Ok(());
         }
 
@@ -105,21 +133,49 @@ impl TimelineMonitor {
         start_time: DateTime<Local>,
         end_time: DateTime<Local>,
     ) -> Result<()> {
-        let ui = TimelineUI::new(timelines, start_time, end_time);
+        let mut ui = TimelineUI::new(timelines, start_time, end_time);
+        if self.watch {
+            ui.mark_updated(Local::now());
+        }
+
+        // Block on input when static; poll with a short timeout in watch mode so
+        // the loop also wakes on the re-scan timer while keeping keys responsive.
+        let poll_timeout = if self.watch {
+            std::time::Duration::from_millis(200)
+        } else {
+            std::time::Duration::from_secs(3600)
+        };
+        let reload_every = std::time::Duration::from_secs(self.interval);
+        let mut last_reload = std::time::Instant::now();
 
         loop {
             terminal.draw(|f| {
                 ui.render(f, f.area());
             })?;
 
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
+            if event::poll(poll_timeout)? {
+                if let Event::Key(key) = event::read()? {
+                    if ui.handle_key(key.code) {
                         break;
                     }
-                    _ => {}
                 }
             }
+
+            // Debounce re-scans to at most once per interval.
+            if self.watch && last_reload.elapsed() >= reload_every {
+                let now = Local::now();
+                let start_time = now - Duration::days(self.days);
+                if let Ok(refreshed) = load_sessions_in_timerange(
+                    start_time,
+                    now,
+                    self.project.as_deref(),
+                    self.threads,
+                ) {
+                    ui.update_timelines(refreshed, start_time, now);
+                    ui.mark_updated(now);
+                }
+                last_reload = std::time::Instant::now();
+            }
         }
 
         Ok(())
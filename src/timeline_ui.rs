@@ -1,10 +1,11 @@
 use crate::claude_logs::SessionTimeline;
+use crate::pricing::PricingTable;
 use chrono::{DateTime, Local, Timelike, Datelike};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
 
@@ -12,6 +13,33 @@ pub struct TimelineUI {
     pub timelines: Vec<SessionTimeline>,
     pub start_time: DateTime<Local>,
     pub end_time: DateTime<Local>,
+    /// Whether the `/`-activated search bar is currently capturing input.
+    search_mode: bool,
+    /// Current search query typed by the user.
+    input: String,
+    /// The query compiled lazily into a regex: `None` while blank, `Some(Err)`
+    /// while the in-progress pattern is invalid. Modeled on bottom's
+    /// `AppSearchState`.
+    searched_pattern: Option<Result<regex::Regex, regex::Error>>,
+    /// True when the (trimmed) query is empty, i.e. "show all".
+    is_blank_search: bool,
+    /// True when the query fails to compile, so the header can warn instead of
+    /// wiping the list.
+    is_invalid_search: bool,
+    /// When set, the query is a fuzzy subsequence pattern scored by run/
+    /// separator bonuses instead of a regex; toggled with Tab while the search
+    /// bar is active. `filtered` is sorted by descending score in this mode.
+    fuzzy_mode: bool,
+    /// Indices into `timelines` that survive the current filter, in display
+    /// order (score order in fuzzy mode). Drives both the rendered row order
+    /// and `selected`'s row highlight.
+    filtered: Vec<usize>,
+    /// Currently highlighted row within `filtered`, highlighted in the table.
+    selected: usize,
+    /// When the data was last refreshed, shown in the header in watch mode.
+    last_updated: Option<DateTime<Local>>,
+    /// Token pricing used to estimate per-session cost.
+    pricing: PricingTable,
 }
 
 impl TimelineUI {
@@ -20,13 +48,163 @@ impl TimelineUI {
         start_time: DateTime<Local>,
         end_time: DateTime<Local>,
     ) -> Self {
+        let filtered = (0..timelines.len()).collect();
         Self {
             timelines,
             start_time,
             end_time,
+            search_mode: false,
+            input: String::new(),
+            searched_pattern: None,
+            is_blank_search: true,
+            is_invalid_search: false,
+            fuzzy_mode: false,
+            filtered,
+            selected: 0,
+            last_updated: None,
+            pricing: PricingTable::load().unwrap_or_default(),
         }
     }
 
+    /// Replace the loaded timelines (e.g. after a watch-mode re-scan), keeping
+    /// the current search query applied and the selection clamped.
+    pub fn update_timelines(
+        &mut self,
+        timelines: Vec<SessionTimeline>,
+        start_time: DateTime<Local>,
+        end_time: DateTime<Local>,
+    ) {
+        self.timelines = timelines;
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self.recompute_filter();
+    }
+
+    /// Record the time of the latest refresh for the header indicator.
+    pub fn mark_updated(&mut self, when: DateTime<Local>) {
+        self.last_updated = Some(when);
+    }
+
+    /// Handle a key press. Returns `true` when the UI should exit.
+    pub fn handle_key(&mut self, code: crossterm::event::KeyCode) -> bool {
+        use crossterm::event::KeyCode;
+
+        if self.search_mode {
+            match code {
+                KeyCode::Esc => {
+                    // Abandon the search and restore the full list.
+                    self.search_mode = false;
+                    self.input.clear();
+                    self.recompute_filter();
+                }
+                KeyCode::Enter => {
+                    // Keep the filter applied but leave input mode.
+                    self.search_mode = false;
+                }
+                KeyCode::Backspace => {
+                    self.input.pop();
+                    self.recompute_filter();
+                }
+                KeyCode::Char(c) => {
+                    self.input.push(c);
+                    self.recompute_filter();
+                }
+                KeyCode::Tab => {
+                    // Swap regex for a fuzzy subsequence match, or back.
+                    self.fuzzy_mode = !self.fuzzy_mode;
+                    self.recompute_filter();
+                }
+                _ => {}
+            }
+            return false;
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return true,
+            KeyCode::Char('/') => {
+                self.search_mode = true;
+                self.input.clear();
+                self.recompute_filter();
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if !self.filtered.is_empty() {
+                    self.selected = (self.selected + 1).min(self.filtered.len() - 1);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            _ => {}
+        }
+        false
+    }
+
+    /// Recompile the query and recompute `filtered` from it. A blank or invalid
+    /// pattern leaves the full list visible so an in-progress regex never wipes
+    /// the view. In fuzzy mode, `filtered` is ordered by descending score
+    /// (ties broken by most-recent `end_time`) rather than timeline order.
+    fn recompute_filter(&mut self) {
+        self.update_search_pattern();
+
+        self.filtered = if self.is_blank_search {
+            (0..self.timelines.len()).collect()
+        } else if self.fuzzy_mode {
+            let query = self.input.trim();
+            let mut scored: Vec<(i32, usize)> = self
+                .timelines
+                .iter()
+                .enumerate()
+                .filter_map(|(i, t)| fuzzy_score(query, &t.project_name).map(|score| (score, i)))
+                .collect();
+            scored.sort_by(|(score_a, idx_a), (score_b, idx_b)| {
+                score_b
+                    .cmp(score_a)
+                    .then_with(|| self.timelines[*idx_b].end_time.cmp(&self.timelines[*idx_a].end_time))
+            });
+            scored.into_iter().map(|(_, i)| i).collect()
+        } else {
+            match &self.searched_pattern {
+                Some(Ok(re)) => self
+                    .timelines
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, t)| timeline_matches(re, t))
+                    .map(|(i, _)| i)
+                    .collect(),
+                _ => (0..self.timelines.len()).collect(),
+            }
+        };
+
+        if self.selected >= self.filtered.len() {
+            self.selected = self.filtered.len().saturating_sub(1);
+        }
+    }
+
+    /// Compile `input` into `searched_pattern`, updating the blank/invalid flags.
+    fn update_search_pattern(&mut self) {
+        let query = self.input.trim();
+        self.is_blank_search = query.is_empty();
+
+        if self.is_blank_search {
+            self.searched_pattern = None;
+            self.is_invalid_search = false;
+            return;
+        }
+
+        // Case-insensitive by default, matching bottom's search ergonomics.
+        let compiled = regex::RegexBuilder::new(query)
+            .case_insensitive(true)
+            .build();
+        self.is_invalid_search = compiled.is_err();
+        self.searched_pattern = Some(compiled);
+    }
+
+    /// The timelines surviving the current filter, in `filtered`'s order
+    /// (score order in fuzzy mode), used by the table and summary.
+    pub fn visible(&self) -> Vec<&SessionTimeline> {
+        self.filtered.iter().map(|&i| &self.timelines[i]).collect()
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -52,7 +230,7 @@ impl TimelineUI {
         let duration = self.end_time.signed_duration_since(self.start_time);
         let hours = duration.num_hours();
 
-        let header_text = vec![
+        let mut lines = vec![
             Line::from(vec![
                 Span::styled("📊 Claude Project Timeline", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
                 Span::raw(" | "),
@@ -61,18 +239,50 @@ impl TimelineUI {
                 Span::raw(self.end_time.format("%m/%d/%Y %H:%M").to_string()),
                 Span::styled(format!(" ({} hours)", hours), Style::default().add_modifier(Modifier::BOLD)),
                 Span::raw(" | "),
-                Span::styled(format!("{} projects", self.timelines.len()), Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{} projects", self.filtered.len()), Style::default().fg(Color::Yellow)),
             ]),
         ];
 
-        let header = Paragraph::new(header_text)
+        if let Some(updated) = self.last_updated {
+            if let Some(line) = lines.first_mut() {
+                line.spans.push(Span::raw(" | "));
+                line.spans.push(Span::styled(
+                    format!("updated {}", updated.format("%H:%M:%S")),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+        }
+
+        if self.search_mode || !self.input.is_empty() {
+            let mut search_line = vec![
+                Span::styled("/", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
+                Span::raw(self.input.clone()),
+                Span::styled(if self.search_mode { "▏" } else { "" }, Style::default().fg(Color::Green)),
+                Span::raw("  "),
+                Span::styled(
+                    if self.fuzzy_mode { "[fuzzy, Tab for regex]" } else { "[regex, Tab for fuzzy]" },
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ];
+            if self.is_invalid_search && !self.fuzzy_mode {
+                search_line.push(Span::raw("  "));
+                search_line.push(Span::styled(
+                    "invalid regex",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ));
+            }
+            lines.push(Line::from(search_line));
+        }
+
+        let header = Paragraph::new(lines)
             .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Blue)));
 
         frame.render_widget(header, area);
     }
 
     fn render_timeline_table(&self, frame: &mut Frame, area: Rect) {
-        if self.timelines.is_empty() {
+        let visible = self.visible();
+        if visible.is_empty() {
             let no_data = Paragraph::new("🔍 No Claude sessions found in the specified time range")
                 .style(Style::default().fg(Color::Yellow))
                 .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)));
@@ -90,8 +300,9 @@ impl TimelineUI {
             &timeline_header,
             "Events",
             "Input",
-            "Output", 
+            "Output",
             "Duration",
+            "Cost",
         ])
         .style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD));
 
@@ -104,12 +315,13 @@ impl TimelineUI {
             "",
             "",
             "",
+            "",
         ]);
 
         // Create data rows
         let mut rows = vec![time_axis_row];
-        
-        for timeline in &self.timelines {
+
+        for (display_idx, timeline) in visible.iter().enumerate() {
             let timeline_str = self.create_timeline_string(timeline, timeline_width);
             let input_tokens = if timeline.total_input_tokens > 0 {
                 Self::format_number(timeline.total_input_tokens)
@@ -122,21 +334,21 @@ impl TimelineUI {
                 "-".to_string()
             };
 
-            let project_display = if let Some(ref _parent) = timeline.parent_project {
-                format!(" └─{}", timeline.project_name)
-            } else {
-                timeline.project_name.clone()
-            };
-
-            let row = Row::new(vec![
-                project_display,
-                timeline_str,
-                timeline.events.len().to_string(),
-                input_tokens,
-                output_tokens,
-                format!("{}m", timeline.active_duration_minutes),
+            let mut row = Row::new(vec![
+                Cell::from(self.project_cell(timeline)),
+                Cell::from(timeline_str),
+                Cell::from(timeline.events.len().to_string()),
+                Cell::from(input_tokens),
+                Cell::from(output_tokens),
+                Cell::from(format!("{}m", timeline.active_duration_minutes)),
+                Cell::from(self.pricing.estimate_timeline(timeline).display()),
             ]);
 
+            // Highlight the row tracked by `self.selected` (moved with j/k).
+            if display_idx == self.selected {
+                row = row.style(Style::default().bg(Color::DarkGray));
+            }
+
             rows.push(row);
         }
 
@@ -147,6 +359,7 @@ impl TimelineUI {
             Constraint::Length(8),   // Input
             Constraint::Length(8),   // Output
             Constraint::Length(8),   // Duration
+            Constraint::Length(12),  // Cost
         ])
         .header(headers)
         .block(Block::default().title("Project Activity").borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)));
@@ -154,23 +367,79 @@ impl TimelineUI {
         frame.render_widget(table, area);
     }
 
+    /// Build the project-name cell, highlighting the span the query matches as
+    /// a regex when one is active. Fuzzy mode highlights that same regex span;
+    /// individual fuzzy-matched characters are not marked.
+    fn project_cell(&self, timeline: &SessionTimeline) -> Line<'static> {
+        let prefix = if timeline.parent_project.is_some() {
+            " └─"
+        } else {
+            ""
+        };
+
+        // Highlight the matched span when a valid query is active.
+        let matched = match &self.searched_pattern {
+            Some(Ok(re)) if !self.is_blank_search => {
+                re.find(&timeline.project_name).map(|m| (m.start(), m.end()))
+            }
+            _ => None,
+        };
+
+        let mut spans = Vec::new();
+        if !prefix.is_empty() {
+            spans.push(Span::raw(prefix.to_string()));
+        }
+
+        match matched {
+            Some((start, end)) => {
+                for (byte, ch) in timeline.project_name.char_indices() {
+                    if byte >= start && byte < end {
+                        spans.push(Span::styled(
+                            ch.to_string(),
+                            Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                        ));
+                    } else {
+                        spans.push(Span::raw(ch.to_string()));
+                    }
+                }
+            }
+            None => spans.push(Span::raw(timeline.project_name.clone())),
+        }
+
+        Line::from(spans)
+    }
+
     fn render_summary(&self, frame: &mut Frame, area: Rect) {
-        if self.timelines.is_empty() {
+        let visible = self.visible();
+        if visible.is_empty() {
             return;
         }
 
-        let total_events: usize = self.timelines.iter().map(|t| t.events.len()).sum();
-        let total_projects = self.timelines.len();
-        
-        let most_active = self.timelines
+        let total_events: usize = visible.iter().map(|t| t.events.len()).sum();
+        let total_projects = visible.len();
+
+        let most_active = visible
             .iter()
             .max_by_key(|t| t.events.len())
             .unwrap();
 
-        let avg_duration: f64 = self.timelines
+        let avg_duration: f64 = (visible
             .iter()
             .map(|t| t.active_duration_minutes as f64)
-            .sum::<f64>() / total_projects as f64;
+            .sum::<f64>()
+            / total_projects as f64)
+            .finite_or_default();
+
+        let total_cost = visible.iter().fold(
+            crate::pricing::CostEstimate { usd: 0.0, unknown: false },
+            |acc, t| {
+                let e = self.pricing.estimate_timeline(t);
+                crate::pricing::CostEstimate {
+                    usd: acc.usd + e.usd,
+                    unknown: acc.unknown || e.unknown,
+                }
+            },
+        );
 
         let summary_text = vec![
             Line::from(vec![
@@ -190,9 +459,13 @@ impl TimelineUI {
             ]),
             Line::from(vec![
                 Span::raw("  • Most Active Project: "),
-                Span::styled(&most_active.project_name, Style::default().fg(Color::Yellow)),
+                Span::styled(most_active.project_name.clone(), Style::default().fg(Color::Yellow)),
                 Span::raw(format!(" ({} events)", most_active.events.len())),
             ]),
+            Line::from(vec![
+                Span::raw("  • Estimated Cost: "),
+                Span::styled(total_cost.display(), Style::default().fg(Color::Yellow)),
+            ]),
         ];
 
         let summary = Paragraph::new(summary_text);
@@ -205,10 +478,16 @@ impl TimelineUI {
 
     fn create_time_axis(&self, width: usize) -> String {
         let total_duration = self.end_time.signed_duration_since(self.start_time);
+        let total_seconds = total_duration.num_seconds();
         let total_hours = total_duration.num_hours();
 
         let mut axis_chars = vec![' '; width];
 
+        // A zero-width range has no meaningful axis; render it blank.
+        if total_seconds == 0 {
+            return axis_chars.into_iter().collect();
+        }
+
         // Simple time markers for now - show every few hours/days
         if total_hours <= 24 {
             // Hour markers
@@ -225,7 +504,8 @@ impl TimelineUI {
             while current <= self.end_time {
                 if current >= self.start_time {
                     let offset_seconds = current.signed_duration_since(self.start_time).num_seconds();
-                    let position = ((offset_seconds as f64 / total_duration.num_seconds() as f64) * (width - 1) as f64) as usize;
+                    let ratio = (offset_seconds as f64 / total_seconds as f64).finite_or_default();
+                    let position = ((ratio * (width - 1) as f64) as usize).min(width.saturating_sub(1));
 
                     if position < width.saturating_sub(2) {
                         let label = format!("{:02}", current.hour());
@@ -253,7 +533,8 @@ impl TimelineUI {
             while current <= self.end_time {
                 if current >= self.start_time {
                     let offset_seconds = current.signed_duration_since(self.start_time).num_seconds();
-                    let position = ((offset_seconds as f64 / total_duration.num_seconds() as f64) * (width - 1) as f64) as usize;
+                    let ratio = (offset_seconds as f64 / total_seconds as f64).finite_or_default();
+                    let position = ((ratio * (width - 1) as f64) as usize).min(width.saturating_sub(1));
 
                     if position < width.saturating_sub(5) {
                         let label = format!("{:02}/{:02}", current.month(), current.day());
@@ -276,15 +557,21 @@ impl TimelineUI {
         let mut activity_counts = vec![0u32; width];
 
         let total_duration = self.end_time.signed_duration_since(self.start_time);
+        let total_seconds = total_duration.num_seconds();
+
+        // A degenerate (zero-width) range has no positions to map onto; render a
+        // flat "no activity" bar rather than dividing by zero.
+        if total_seconds == 0 {
+            return "·".repeat(width);
+        }
 
         // Count events per position
         for event in &timeline.events {
             let event_offset = event.timestamp.signed_duration_since(self.start_time);
-            let position = ((event_offset.num_seconds() as f64 / total_duration.num_seconds() as f64) * width as f64) as usize;
-            
-            if position < width {
-                activity_counts[position] += 1;
-            }
+            let ratio = (event_offset.num_seconds() as f64 / total_seconds as f64).finite_or_default();
+            let position = ((ratio * width as f64) as usize).min(width.saturating_sub(1));
+
+            activity_counts[position] += 1;
         }
 
         // Find max activity for normalization
@@ -296,7 +583,8 @@ impl TimelineUI {
             if count == 0 {
                 timeline_chars[i] = '·'; // Low activity
             } else {
-                let density = ((count as f64 / max_activity as f64) * 4.0) as u32;
+                let density =
+                    ((count as f64 / max_activity as f64) * 4.0).finite_or_default() as u32;
                 timeline_chars[i] = match density {
                     0 => '·',
                     1 => '▪',
@@ -315,14 +603,80 @@ impl TimelineUI {
         let num_str = num.to_string();
         let len = num_str.len();
         let mut result = String::new();
-        
+
         for (i, ch) in num_str.chars().enumerate() {
             if i > 0 && (len - i) % 3 == 0 {
                 result.push(',');
             }
             result.push(ch);
         }
-        
+
         result
     }
-}
\ No newline at end of file
+}
+
+/// Turn a non-finite float into a safe default, so a zero-width range or empty
+/// set never yields NaN/∞ positions or densities.
+pub trait FiniteOr {
+    /// Return `self` when finite, otherwise `0.0`.
+    fn finite_or_default(self) -> f64;
+}
+
+impl FiniteOr for f64 {
+    fn finite_or_default(self) -> f64 {
+        if self.is_finite() {
+            self
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Whether a timeline matches the compiled query on its project name or parent.
+fn timeline_matches(re: &regex::Regex, timeline: &SessionTimeline) -> bool {
+    re.is_match(&timeline.project_name)
+        || timeline
+            .parent_project
+            .as_deref()
+            .map_or(false, |parent| re.is_match(parent))
+}
+
+/// Score `candidate` against `query` as a case-insensitive subsequence match,
+/// or `None` when `query`'s characters don't all appear in order.
+///
+/// Consecutive matched characters (a "run") score higher than scattered ones,
+/// and a character matched right at a word boundary (start of string, or just
+/// after `-`/`_`/`/`/`.`/space) scores higher still — the same heuristic
+/// fuzzy-finders like fzf and bottom use so that e.g. `ccm` ranks
+/// `claude-code-monitor` above `accumulate`.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_lower = query.to_lowercase();
+    let candidate_lower = candidate.to_lowercase();
+    let chars: Vec<char> = candidate_lower.chars().collect();
+
+    let mut score = 0i32;
+    let mut cursor = 0usize;
+    let mut prev_match: Option<usize> = None;
+
+    for qc in query_lower.chars() {
+        let offset = chars[cursor..].iter().position(|&c| c == qc)?;
+        let idx = cursor + offset;
+
+        score += 1;
+        if prev_match == Some(idx.wrapping_sub(1)) {
+            score += 5; // consecutive run bonus
+        }
+        if idx == 0 || matches!(chars[idx - 1], '-' | '_' | '/' | '.' | ' ') {
+            score += 3; // word-boundary bonus
+        }
+
+        prev_match = Some(idx);
+        cursor = idx + 1;
+    }
+
+    Some(score)
+}
@@ -12,28 +12,93 @@ use ratatui::{
     symbols,
     text::{Line, Span},
     widgets::{
-        Axis, Block, Borders, Cell, Chart, Dataset, Gauge, Paragraph, Row, Table, Tabs, Wrap,
+        Axis, Block, Borders, Cell, Chart, Clear, Dataset, Gauge, Paragraph, Row, Table,
+        TableState, Tabs, Wrap,
     },
     Frame, Terminal,
 };
 use std::{
+    collections::HashMap,
     io,
     time::{Duration, Instant},
 };
 
 use crate::{
     db::{ProcessMetric, ProcessStats},
-    process::{ClaudeTask, ProcessInfo},
-    utils::{format_bytes, format_duration},
+    process::{sort_tasks, ClaudeTask, Priority, ProcessHistory, ProcessInfo, SPARKLINE_WIDTH},
+    utils::{format_bytes, format_runtime},
 };
 
 pub struct UI {
     terminal: Terminal<CrosstermBackend<io::Stdout>>,
     selected_tab: usize,
+    show_help: bool,
+    /// Scroll/selection state for the Processes tab table.
+    process_state: TableState,
+    /// Scroll/selection state for the Tasks tab table.
+    task_state: TableState,
+    /// Latest process snapshot, cached each draw so input handling can map the
+    /// selection to a concrete PID and clamp the selection as the list changes.
+    processes: Vec<ProcessInfo>,
+    /// Latest task snapshot, cached each draw for selection clamping.
+    tasks: Vec<ClaudeTask>,
+    /// Set after the first `d` of the `dd` kill sequence.
+    awaiting_d: bool,
+    /// PID and name awaiting kill confirmation, shown as a modal.
+    pending_kill: Option<(u32, String)>,
+    /// PID the UI wants the monitor to terminate; consumed via `take_kill_request`.
+    kill_request: Option<u32>,
+    /// Column the process table is sorted by.
+    sort_column: SortColumn,
+    /// Whether the sort is descending.
+    sort_desc: bool,
+    /// Compact mode: no charts, condensed stats, bigger process list.
+    basic: bool,
+    /// Accent colors for the UI, from config.
+    theme: Theme,
+}
+
+/// Accent colors used throughout the UI, resolved from config at startup.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Primary accent (title, memory chart).
+    pub primary: Color,
+    /// Secondary accent (tab highlight, table headers, CPU chart).
+    pub accent: Color,
+    /// Alert color (maxima, destructive actions).
+    pub alert: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: Color::Cyan,
+            accent: Color::Yellow,
+            alert: Color::Red,
+        }
+    }
+}
+
+/// Column used to sort the process table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Pid,
+    Name,
+    Cpu,
+    Memory,
 }
 
 impl UI {
     pub fn new() -> Result<Self> {
+        Self::with_basic(false)
+    }
+
+    pub fn with_basic(basic: bool) -> Result<Self> {
+        Self::with_settings(basic, Theme::default(), 0)
+    }
+
+    /// Build the UI with a resolved theme and a startup tab, both from config.
+    pub fn with_settings(basic: bool, theme: Theme, default_tab: usize) -> Result<Self> {
         // Setup terminal
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -43,7 +108,19 @@ impl UI {
 
         Ok(Self {
             terminal,
-            selected_tab: 0,
+            selected_tab: default_tab,
+            show_help: false,
+            process_state: TableState::default().with_selected(Some(0)),
+            task_state: TableState::default().with_selected(Some(0)),
+            processes: Vec::new(),
+            tasks: Vec::new(),
+            awaiting_d: false,
+            pending_kill: None,
+            kill_request: None,
+            sort_column: SortColumn::Cpu,
+            sort_desc: true,
+            basic,
+            theme,
         })
     }
 
@@ -54,7 +131,22 @@ impl UI {
         cpu_history: &[(DateTime<Local>, f32)],
         memory_history: &[(DateTime<Local>, f64)],
         tasks: &[ClaudeTask],
+        histories: &HashMap<u32, ProcessHistory>,
     ) -> Result<()> {
+        // Cache the latest snapshot and keep both selections in range as the
+        // process/task lists change length between refreshes.
+        self.processes = processes.to_vec();
+        sort_processes(self.sort_column, self.sort_desc, &mut self.processes);
+        self.tasks = tasks.to_vec();
+        sort_tasks(&mut self.tasks);
+        clamp_selection(&mut self.process_state, self.processes.len());
+        clamp_selection(&mut self.task_state, self.tasks.len());
+
+        // Move the stateful selections out so the draw closure can borrow them
+        // mutably without aliasing `self.terminal`.
+        let mut process_state = std::mem::take(&mut self.process_state);
+        let mut task_state = std::mem::take(&mut self.task_state);
+
         self.terminal.draw(|f| {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
@@ -77,19 +169,81 @@ impl UI {
 
             // Content based on selected tab
             match self.selected_tab {
-                0 => self.render_overview(f, chunks[2], processes, stats, cpu_history, memory_history),
-                1 => self.render_processes(f, chunks[2], processes),
-                2 => self.render_tasks(f, chunks[2], tasks),
+                0 => self.render_overview(f, chunks[2], processes, stats, cpu_history, memory_history, histories),
+                1 => self.render_processes(f, chunks[2], processes, &mut process_state, histories),
+                2 => self.render_tasks(f, chunks[2], &self.tasks, &mut task_state),
                 _ => {}
             }
+
+            // Help overlay renders on top of whatever tab is active.
+            if self.show_help {
+                self.render_help(f);
+            }
+
+            // Kill confirmation takes precedence over everything.
+            if let Some((pid, name)) = &self.pending_kill {
+                self.render_kill_confirm(f, *pid, name);
+            }
         })?;
 
+        // Restore the (possibly scrolled) selection state.
+        self.process_state = process_state;
+        self.task_state = task_state;
+
         Ok(())
     }
 
+    /// Render a centered help modal listing the controls, grouped by context.
+    fn render_help(&self, f: &mut Frame) {
+        let area = centered_rect(60, 60, f.area());
+
+        let help_text = vec![
+            Line::from(Span::styled(
+                "General",
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )),
+            Line::from("  Tab / BackTab   Switch tabs"),
+            Line::from("  ?               Toggle this help"),
+            Line::from("  q / Esc         Quit"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Processes",
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )),
+            Line::from("  j / k, ↑ / ↓    Move selection"),
+            Line::from("  PageUp / PageDown  Move selection a page at a time"),
+            Line::from("  dd              Kill the selected process (confirm with y)"),
+            Line::from("  c / m / p / n   Sort by CPU / Memory / PID / Name (again to reverse)"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Tasks",
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )),
+            Line::from("  j / k, ↑ / ↓    Move selection"),
+            Line::from("  PageUp / PageDown  Move selection a page at a time"),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Press ? or Esc to close",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+
+        let modal = Paragraph::new(help_text)
+            .block(
+                Block::default()
+                    .title("❓ Help")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(Clear, area);
+        f.render_widget(modal, area);
+    }
+
     fn render_title(&self, f: &mut Frame, area: Rect) {
         let title = Paragraph::new("🖥️  Claude Code Monitor")
-            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .style(Style::default().fg(self.theme.primary).add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center)
             .block(Block::default().borders(Borders::BOTTOM));
         f.render_widget(title, area);
@@ -103,7 +257,7 @@ impl UI {
             .style(Style::default().fg(Color::White))
             .highlight_style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(self.theme.accent)
                     .add_modifier(Modifier::BOLD),
             );
         f.render_widget(tabs, area);
@@ -117,7 +271,29 @@ impl UI {
         stats: &ProcessStats,
         cpu_history: &[(DateTime<Local>, f32)],
         memory_history: &[(DateTime<Local>, f64)],
+        histories: &HashMap<u32, ProcessHistory>,
     ) {
+        // Basic mode drops the charts entirely and gives that space to the
+        // process list, with a condensed one/two-line stats header. Useful for
+        // narrow terminals, tmux panes, or terminals where braille renders poorly.
+        if self.basic {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Length(3), // Condensed stats
+                        Constraint::Min(0),    // Process list
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+
+            self.render_stats_compact(f, chunks[0], stats);
+            // The Overview list is read-only context; no persistent selection.
+            self.render_process_list(f, chunks[1], processes, &mut TableState::default(), histories);
+            return;
+        }
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(
@@ -143,7 +319,31 @@ impl UI {
         self.render_memory_chart(f, chart_chunks[1], memory_history);
 
         // Process list
-        self.render_process_list(f, chunks[2], processes);
+        self.render_process_list(f, chunks[2], processes, &mut TableState::default(), histories);
+    }
+
+    /// Condensed single-line stats for basic mode.
+    fn render_stats_compact(&self, f: &mut Frame, area: Rect, stats: &ProcessStats) {
+        let line = Line::from(vec![
+            Span::raw("Procs: "),
+            Span::styled(format!("{}", stats.process_count), Style::default().fg(Color::Green)),
+            Span::raw("  CPU: "),
+            Span::styled(format!("{:.1}%", stats.avg_cpu), Style::default().fg(self.theme.accent)),
+            Span::raw(format!(" (max {:.1}%)", stats.max_cpu)),
+            Span::raw("  Mem: "),
+            Span::styled(
+                format_bytes((stats.avg_memory * 1024.0 * 1024.0) as u64),
+                Style::default().fg(self.theme.accent),
+            ),
+            Span::raw(format!(
+                " (max {})",
+                format_bytes((stats.max_memory * 1024.0 * 1024.0) as u64)
+            )),
+        ]);
+
+        let paragraph = Paragraph::new(line)
+            .block(Block::default().title("📊 Statistics").borders(Borders::ALL));
+        f.render_widget(paragraph, area);
     }
 
     fn render_stats(&self, f: &mut Frame, area: Rect, stats: &ProcessStats) {
@@ -159,12 +359,12 @@ impl UI {
                 Span::raw("CPU Usage: "),
                 Span::styled(
                     format!("{:.1}%", stats.avg_cpu),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.accent),
                 ),
                 Span::raw(" (max: "),
                 Span::styled(
                     format!("{:.1}%", stats.max_cpu),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(self.theme.alert),
                 ),
                 Span::raw(")"),
             ]),
@@ -172,12 +372,12 @@ impl UI {
                 Span::raw("Memory Usage: "),
                 Span::styled(
                     format_bytes((stats.avg_memory * 1024.0 * 1024.0) as u64),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(self.theme.accent),
                 ),
                 Span::raw(" (max: "),
                 Span::styled(
                     format_bytes((stats.max_memory * 1024.0 * 1024.0) as u64),
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(self.theme.alert),
                 ),
                 Span::raw(")"),
             ]),
@@ -208,7 +408,7 @@ impl UI {
         let dataset = Dataset::default()
             .name("CPU %")
             .marker(symbols::Marker::Braille)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(self.theme.accent))
             .data(&data);
 
         let chart = Chart::new(vec![dataset])
@@ -256,7 +456,7 @@ impl UI {
         let dataset = Dataset::default()
             .name("Memory MB")
             .marker(symbols::Marker::Braille)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(self.theme.primary))
             .data(&data);
 
         let chart = Chart::new(vec![dataset])
@@ -284,20 +484,55 @@ impl UI {
         f.render_widget(chart, area);
     }
 
-    fn render_process_list(&self, f: &mut Frame, area: Rect, processes: &[ProcessInfo]) {
-        let header = Row::new(vec!["PID", "Name", "CPU %", "Memory", "Runtime", "Status"])
-            .style(Style::default().fg(Color::Yellow))
-            .bottom_margin(1);
-
+    fn render_process_list(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        processes: &[ProcessInfo],
+        state: &mut TableState,
+        histories: &HashMap<u32, ProcessHistory>,
+    ) {
+        // Mark the active sort column with a direction arrow.
+        let arrow = if self.sort_desc { " ▼" } else { " ▲" };
+        let col = |label: &str, column: SortColumn| -> String {
+            if self.sort_column == column {
+                format!("{}{}", label, arrow)
+            } else {
+                label.to_string()
+            }
+        };
+        let header = Row::new(vec![
+            col("PID", SortColumn::Pid),
+            col("Name", SortColumn::Name),
+            col("CPU %", SortColumn::Cpu),
+            "CPU Trend".to_string(),
+            col("Memory", SortColumn::Memory),
+            "Mem Trend".to_string(),
+            "Runtime".to_string(),
+            "Status".to_string(),
+        ])
+        .style(Style::default().fg(self.theme.accent))
+        .bottom_margin(1);
+
+        // Sort a local copy so the displayed order matches the cached selection.
+        let mut processes = processes.to_vec();
+        sort_processes(self.sort_column, self.sort_desc, &mut processes);
+
+        let blank_history = ProcessHistory::default();
         let rows: Vec<Row> = processes
             .iter()
             .map(|p| {
+                let history = histories.get(&p.pid).unwrap_or(&blank_history);
                 Row::new(vec![
                     Cell::from(p.pid.to_string()),
                     Cell::from(p.name.clone()),
                     Cell::from(format!("{:.1}", p.cpu_percent)),
+                    Cell::from(history.cpu_sparkline.clone())
+                        .style(Style::default().fg(self.theme.accent)),
                     Cell::from(format_bytes((p.memory_mb * 1024.0 * 1024.0) as u64)),
-                    Cell::from(format_duration(p.runtime_seconds)),
+                    Cell::from(history.mem_sparkline.clone())
+                        .style(Style::default().fg(self.theme.primary)),
+                    Cell::from(format_runtime(p.runtime_seconds)),
                     Cell::from(p.status.clone()),
                 ])
             })
@@ -309,7 +544,9 @@ impl UI {
                 Constraint::Length(8),
                 Constraint::Min(20),
                 Constraint::Length(8),
+                Constraint::Length(SPARKLINE_WIDTH as u16 + 2),
                 Constraint::Length(10),
+                Constraint::Length(SPARKLINE_WIDTH as u16 + 2),
                 Constraint::Length(10),
                 Constraint::Min(10),
             ],
@@ -319,28 +556,108 @@ impl UI {
             Block::default()
                 .title("🔍 Active Processes")
                 .borders(Borders::ALL),
-        );
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
 
-        f.render_widget(table, area);
+        f.render_stateful_widget(table, area, state);
     }
 
-    fn render_processes(&self, f: &mut Frame, area: Rect, processes: &[ProcessInfo]) {
-        self.render_process_list(f, area, processes);
+    fn render_processes(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        processes: &[ProcessInfo],
+        state: &mut TableState,
+        histories: &HashMap<u32, ProcessHistory>,
+    ) {
+        self.render_process_list(f, area, processes, state, histories);
     }
 
-    fn render_tasks(&self, f: &mut Frame, area: Rect, tasks: &[ClaudeTask]) {
-        let header = Row::new(vec!["ID", "Name", "Status", "Created", "Updated"])
-            .style(Style::default().fg(Color::Yellow))
+    /// Render the `dd` kill confirmation modal naming the target process.
+    fn render_kill_confirm(&self, f: &mut Frame, pid: u32, name: &str) {
+        let area = centered_rect(50, 25, f.area());
+
+        let text = vec![
+            Line::from(vec![
+                Span::raw("Kill process "),
+                Span::styled(
+                    format!("{} (PID {})", name, pid),
+                    Style::default().fg(self.theme.alert).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("?"),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "y = confirm    Esc = cancel",
+                Style::default().fg(Color::DarkGray),
+            )),
+        ];
+
+        let modal = Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title("⚠️  Confirm Kill")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.alert)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(Clear, area);
+        f.render_widget(modal, area);
+    }
+
+    /// Take and clear a pending kill request, if any.
+    pub fn take_kill_request(&mut self) -> Option<u32> {
+        self.kill_request.take()
+    }
+
+    /// Select a sort column, reversing direction if it is already active.
+    fn set_sort(&mut self, column: SortColumn) {
+        if self.sort_column == column {
+            self.sort_desc = !self.sort_desc;
+        } else {
+            self.sort_column = column;
+            self.sort_desc = true;
+        }
+    }
+
+    /// Colour for a task priority: high borrows the alert colour, medium the
+    /// accent colour, low stays muted.
+    fn priority_color(&self, priority: Priority) -> Color {
+        match priority {
+            Priority::High => self.theme.alert,
+            Priority::Medium => self.theme.accent,
+            Priority::Low => Color::DarkGray,
+        }
+    }
+
+    fn render_tasks(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        tasks: &[ClaudeTask],
+        state: &mut TableState,
+    ) {
+        let header = Row::new(vec!["ID", "Name", "Status", "Priority", "Logged", "Updated"])
+            .style(Style::default().fg(self.theme.accent))
             .bottom_margin(1);
 
         let rows: Vec<Row> = tasks
             .iter()
             .map(|t| {
+                let priority = match t.priority {
+                    Some(p) => Cell::from(p.label())
+                        .style(Style::default().fg(self.priority_color(p))),
+                    None => Cell::from("-").style(Style::default().fg(Color::DarkGray)),
+                };
                 Row::new(vec![
                     Cell::from(t.id.clone()),
                     Cell::from(t.name.clone()),
                     Cell::from(t.status.clone()),
-                    Cell::from(t.created_at.format("%Y-%m-%d %H:%M").to_string()),
+                    priority,
+                    Cell::from(format_logged(t.total_logged())),
                     Cell::from(t.updated_at.format("%Y-%m-%d %H:%M").to_string()),
                 ])
             })
@@ -351,8 +668,9 @@ impl UI {
             &[
                 Constraint::Length(10),
                 Constraint::Min(30),
-                Constraint::Length(15),
-                Constraint::Length(16),
+                Constraint::Length(12),
+                Constraint::Length(8),
+                Constraint::Length(8),
                 Constraint::Length(16),
             ],
         )
@@ -361,16 +679,93 @@ impl UI {
             Block::default()
                 .title("📋 Claude Tasks")
                 .borders(Borders::ALL),
-        );
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
 
-        f.render_widget(table, area);
+        f.render_stateful_widget(table, area, state);
     }
 
     pub fn handle_input(&mut self) -> Result<bool> {
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
+                // While a kill is pending, capture only the confirm/cancel keys.
+                if self.pending_kill.is_some() {
+                    match key.code {
+                        KeyCode::Char('y') => {
+                            if let Some((pid, _)) = self.pending_kill.take() {
+                                self.kill_request = Some(pid);
+                            }
+                        }
+                        KeyCode::Esc | KeyCode::Char('n') => {
+                            self.pending_kill = None;
+                        }
+                        _ => {}
+                    }
+                    return Ok(false);
+                }
+
+                // Any key other than the second `d` cancels a half-typed `dd`.
+                let was_awaiting_d = self.awaiting_d;
+                if key.code != KeyCode::Char('d') {
+                    self.awaiting_d = false;
+                }
+
                 match key.code {
+                    // Esc closes the help overlay without quitting when it is open.
+                    KeyCode::Esc if self.show_help => {
+                        self.show_help = false;
+                    }
                     KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+                    KeyCode::Char('?') => {
+                        self.show_help = !self.show_help;
+                    }
+                    // Table navigation scrolls the selection within the active
+                    // tab only (Processes tab 1, Tasks tab 2).
+                    KeyCode::Up | KeyCode::Char('k') if self.selected_tab == 1 => {
+                        move_selection(&mut self.process_state, self.processes.len(), -1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if self.selected_tab == 1 => {
+                        move_selection(&mut self.process_state, self.processes.len(), 1);
+                    }
+                    KeyCode::PageUp if self.selected_tab == 1 => {
+                        move_selection(&mut self.process_state, self.processes.len(), -PAGE_STEP);
+                    }
+                    KeyCode::PageDown if self.selected_tab == 1 => {
+                        move_selection(&mut self.process_state, self.processes.len(), PAGE_STEP);
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if self.selected_tab == 2 => {
+                        move_selection(&mut self.task_state, self.tasks.len(), -1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if self.selected_tab == 2 => {
+                        move_selection(&mut self.task_state, self.tasks.len(), 1);
+                    }
+                    KeyCode::PageUp if self.selected_tab == 2 => {
+                        move_selection(&mut self.task_state, self.tasks.len(), -PAGE_STEP);
+                    }
+                    KeyCode::PageDown if self.selected_tab == 2 => {
+                        move_selection(&mut self.task_state, self.tasks.len(), PAGE_STEP);
+                    }
+                    // The `dd` kill sequence acts on the Processes selection.
+                    KeyCode::Char('d') if self.selected_tab == 1 => {
+                        if was_awaiting_d {
+                            self.awaiting_d = false;
+                            if let Some(p) = self
+                                .process_state
+                                .selected()
+                                .and_then(|i| self.processes.get(i))
+                            {
+                                self.pending_kill = Some((p.pid, p.name.clone()));
+                            }
+                        } else {
+                            self.awaiting_d = true;
+                        }
+                    }
+                    // Sort-column keys: pressing the active column reverses order.
+                    KeyCode::Char('c') => self.set_sort(SortColumn::Cpu),
+                    KeyCode::Char('m') => self.set_sort(SortColumn::Memory),
+                    KeyCode::Char('p') => self.set_sort(SortColumn::Pid),
+                    KeyCode::Char('n') => self.set_sort(SortColumn::Name),
                     KeyCode::Tab => {
                         self.selected_tab = (self.selected_tab + 1) % 3;
                     }
@@ -392,6 +787,7 @@ impl UI {
         &mut self,
         stats: &ProcessStats,
         processes: &[ProcessInfo],
+        histories: &HashMap<u32, ProcessHistory>,
     ) -> Result<()> {
         self.terminal.draw(|f| {
             let chunks = Layout::default()
@@ -414,13 +810,92 @@ impl UI {
             self.render_stats(f, chunks[1], stats);
 
             // Process list
-            self.render_process_list(f, chunks[2], processes);
+            self.render_process_list(f, chunks[2], processes, &mut TableState::default(), histories);
         })?;
 
         Ok(())
     }
 }
 
+/// Rows moved per PageUp/PageDown keypress.
+const PAGE_STEP: isize = 10;
+
+/// Move a table selection by `delta` rows, clamped to `[0, len)`. Clears the
+/// selection on an empty table.
+fn move_selection(state: &mut TableState, len: usize, delta: isize) {
+    if len == 0 {
+        state.select(None);
+        return;
+    }
+    let current = state.selected().unwrap_or(0) as isize;
+    let next = (current + delta).clamp(0, len as isize - 1);
+    state.select(Some(next as usize));
+}
+
+/// Format a logged-time total compactly (`3h 20m`, `45m`), or `-` for none.
+fn format_logged(duration: chrono::Duration) -> String {
+    let minutes = duration.num_minutes();
+    if minutes <= 0 {
+        return "-".to_string();
+    }
+    let hours = minutes / 60;
+    let mins = minutes % 60;
+    if hours > 0 {
+        format!("{}h {}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+/// Keep a table selection in range as the underlying list changes length,
+/// clearing it when the list becomes empty.
+fn clamp_selection(state: &mut TableState, len: usize) {
+    if len == 0 {
+        state.select(None);
+    } else {
+        let selected = state.selected().unwrap_or(0).min(len - 1);
+        state.select(Some(selected));
+    }
+}
+
+/// Sort a process list in place by the given column and direction.
+fn sort_processes(column: SortColumn, desc: bool, processes: &mut [ProcessInfo]) {
+    match column {
+        SortColumn::Pid => processes.sort_by_key(|p| p.pid),
+        SortColumn::Name => processes.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+        SortColumn::Cpu => {
+            processes.sort_by(|a, b| a.cpu_percent.partial_cmp(&b.cpu_percent).unwrap_or(std::cmp::Ordering::Equal))
+        }
+        SortColumn::Memory => {
+            processes.sort_by(|a, b| a.memory_mb.partial_cmp(&b.memory_mb).unwrap_or(std::cmp::Ordering::Equal))
+        }
+    }
+    if desc {
+        processes.reverse();
+    }
+}
+
+/// Compute a `Rect` centered within `area`, sized as a percentage of it.
+fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
 impl Drop for UI {
     fn drop(&mut self) {
         let _ = disable_raw_mode();
@@ -2,15 +2,15 @@ use anyhow::Result;
 use dirs::home_dir;
 use std::path::PathBuf;
 
-/// Get the path to the ccmonitor data directory
+/// Get the path to the ccstat data directory
 pub fn get_data_dir() -> Result<PathBuf> {
     let mut path = home_dir()
         .ok_or_else(|| anyhow::anyhow!("Failed to get home directory"))?;
-    path.push(".ccmonitor");
+    path.push(".ccstat");
     Ok(path)
 }
 
-/// Get the path to the ccmonitor database file
+/// Get the path to the ccstat database file
 pub fn get_db_path() -> Result<PathBuf> {
     let mut path = get_data_dir()?;
     path.push("data.db");
@@ -54,4 +54,31 @@ pub fn format_duration(seconds: u64) -> String {
     } else {
         format!("{:02}:{:02}", minutes, secs)
     }
+}
+
+/// Upper bound on a plausible process runtime (~10 years). Some platforms
+/// (notably Windows) occasionally report the Unix epoch as a start time, which
+/// yields an absurd runtime we'd rather show as "unknown".
+const MAX_PLAUSIBLE_RUNTIME_SECS: u64 = 10 * 365 * 24 * 3600;
+
+/// Format a process runtime compactly (`1h 23m`, `45s`).
+///
+/// Returns `"unknown"` for implausible values — a sub-second runtime or one
+/// larger than [`MAX_PLAUSIBLE_RUNTIME_SECS`] — rather than displaying garbage.
+pub fn format_runtime(seconds: u64) -> String {
+    if seconds == 0 || seconds > MAX_PLAUSIBLE_RUNTIME_SECS {
+        return "unknown".to_string();
+    }
+
+    let hours = seconds / 3600;
+    let minutes = (seconds % 3600) / 60;
+    let secs = seconds % 60;
+
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {}s", minutes, secs)
+    } else {
+        format!("{}s", secs)
+    }
 }
\ No newline at end of file
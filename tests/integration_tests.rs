@@ -1,4 +1,8 @@
-use ccmonitor::{git_utils, claude_logs, SessionEvent, SessionTimeline};
+use ccmonitor::{fuzzy_score, git_utils, claude_logs, parse_git_url, FiniteOr, SessionEvent, SessionTimeline};
+use ccmonitor::db::{Database, RetentionPolicy};
+use ccmonitor::pricing::PricingTable;
+use ccmonitor::process::{parse_duration_seconds, ProcessInfo};
+use ccmonitor::utils::format_runtime;
 use chrono::{DateTime, Local, TimeZone};
 use std::path::PathBuf;
 use tempfile::tempdir;
@@ -23,6 +27,7 @@ fn test_session_event_creation() {
         uuid: "test-uuid".to_string(),
         input_tokens: 10,
         output_tokens: 20,
+        model: None,
     };
 
     assert_eq!(event.session_id, "test-session");
@@ -45,6 +50,7 @@ fn test_active_duration_calculation() {
             uuid: "uuid1".to_string(),
             input_tokens: 0,
             output_tokens: 0,
+            model: None,
         },
         SessionEvent {
             timestamp: base_time + chrono::Duration::seconds(30),
@@ -55,6 +61,7 @@ fn test_active_duration_calculation() {
             uuid: "uuid2".to_string(),
             input_tokens: 10,
             output_tokens: 20,
+            model: None,
         },
     ];
 
@@ -79,6 +86,7 @@ fn test_token_calculation() {
             uuid: "uuid1".to_string(),
             input_tokens: 0,
             output_tokens: 0,
+            model: None,
         },
         SessionEvent {
             timestamp: Local::now(),
@@ -89,6 +97,7 @@ fn test_token_calculation() {
             uuid: "uuid2".to_string(),
             input_tokens: 100,
             output_tokens: 50,
+            model: None,
         },
         SessionEvent {
             timestamp: Local::now(),
@@ -99,6 +108,7 @@ fn test_token_calculation() {
             uuid: "uuid3".to_string(),
             input_tokens: 75,
             output_tokens: 25,
+            model: None,
         },
     ];
 
@@ -117,4 +127,131 @@ fn test_parse_empty_jsonl() {
     let result = claude_logs::parse_jsonl_file(&file_path);
     assert!(result.is_ok());
     assert!(result.unwrap().is_empty());
+}
+
+#[test]
+fn test_fuzzy_score_subsequence_and_order() {
+    // All query characters must appear, in order.
+    assert!(fuzzy_score("ccm", "claude-code-monitor").is_some());
+    assert_eq!(fuzzy_score("zzz", "claude-code-monitor"), None);
+    assert_eq!(fuzzy_score("mcc", "claude-code-monitor"), None);
+    // An empty query matches everything with no preference.
+    assert_eq!(fuzzy_score("", "anything"), Some(0));
+}
+
+#[test]
+fn test_fuzzy_score_prefers_runs_and_word_boundaries() {
+    // A consecutive run ("cc" in "ccstat") scores higher than the same two
+    // letters scattered across separators ("c...c" in "a-c-b-c").
+    let run_score = fuzzy_score("cc", "ccstat").unwrap();
+    let scattered_score = fuzzy_score("cc", "a-c-b-c").unwrap();
+    assert!(run_score > scattered_score);
+
+    // A match right after a separator scores higher than the same letter mid-word.
+    let boundary_score = fuzzy_score("c", "a-cat").unwrap();
+    let mid_word_score = fuzzy_score("c", "abcat").unwrap();
+    assert!(boundary_score > mid_word_score);
+}
+
+#[test]
+fn test_parse_git_url_scp_like_with_subgroups() {
+    let info = parse_git_url("git@gitlab.com:group/subgroup/repo.git").unwrap();
+    assert_eq!(info.host.as_deref(), Some("gitlab.com"));
+    assert_eq!(info.owner.as_deref(), Some("group/subgroup"));
+    assert_eq!(info.name, "repo");
+}
+
+#[test]
+fn test_parse_git_url_scheme_with_port() {
+    let info = parse_git_url("ssh://git@example.com:2222/user/repo.git").unwrap();
+    assert_eq!(info.host.as_deref(), Some("example.com"));
+    assert_eq!(info.owner.as_deref(), Some("user"));
+    assert_eq!(info.name, "repo");
+}
+
+#[test]
+fn test_parse_git_url_bare_local_path_has_no_owner() {
+    let info = parse_git_url("/srv/git/repo.git").unwrap();
+    assert_eq!(info.host, None);
+    assert_eq!(info.owner, None);
+    assert_eq!(info.name, "repo");
+}
+
+#[test]
+fn test_parse_duration_seconds_suffixes() {
+    assert_eq!(parse_duration_seconds("45s").unwrap(), 45);
+    assert_eq!(parse_duration_seconds("30m").unwrap(), 1800);
+    assert_eq!(parse_duration_seconds("2h").unwrap(), 7200);
+    assert_eq!(parse_duration_seconds("1d").unwrap(), 86_400);
+    assert_eq!(parse_duration_seconds("10").unwrap(), 10);
+    assert!(parse_duration_seconds("abc").is_err());
+}
+
+#[test]
+fn test_format_runtime_clamps_implausible_values() {
+    assert_eq!(format_runtime(0), "unknown");
+    assert_eq!(format_runtime(10 * 365 * 24 * 3600 + 1), "unknown");
+    assert_eq!(format_runtime(45), "45s");
+    assert_eq!(format_runtime(90), "1m 30s");
+    assert_eq!(format_runtime(3661), "1h 1m");
+}
+
+#[test]
+fn test_pricing_table_price_for_longest_prefix_match() {
+    let table = PricingTable::default();
+
+    // Exact match.
+    assert!(table.price_for("claude-3-5-sonnet").is_some());
+
+    // A version-date suffix should still resolve, via the longest table key
+    // the model name starts with.
+    let dated = table.price_for("claude-sonnet-4-20250514").unwrap();
+    let exact = table.price_for("claude-sonnet-4").unwrap();
+    assert_eq!(dated.input_per_million, exact.input_per_million);
+    assert_eq!(dated.output_per_million, exact.output_per_million);
+
+    // An unrecognized model has no price.
+    assert!(table.price_for("some-other-vendor-model").is_none());
+}
+
+#[test]
+fn test_finite_or_default_guards_non_finite_floats() {
+    assert_eq!(f64::NAN.finite_or_default(), 0.0);
+    assert_eq!(f64::INFINITY.finite_or_default(), 0.0);
+    assert_eq!(f64::NEG_INFINITY.finite_or_default(), 0.0);
+    assert_eq!(3.5_f64.finite_or_default(), 3.5);
+}
+
+#[test]
+fn test_enforce_retention_drops_rows_older_than_max_age() {
+    let dir = tempdir().unwrap();
+    let mut db = Database::open(dir.path().join("test.db")).unwrap();
+
+    let make_process = |pid: u32, timestamp: DateTime<Local>| ProcessInfo {
+        pid,
+        name: "claude".to_string(),
+        cpu_percent: 1.0,
+        memory_mb: 10.0,
+        runtime_seconds: 60,
+        timestamp,
+        status: "Run".to_string(),
+        cmd: vec!["claude".to_string()],
+    };
+
+    db.insert_process_metrics(&make_process(1, Local::now() - chrono::Duration::days(30)))
+        .unwrap();
+    db.insert_process_metrics(&make_process(2, Local::now()))
+        .unwrap();
+
+    db.enforce_retention(&RetentionPolicy {
+        max_total_bytes: None,
+        max_sessions_per_project: None,
+        max_events_per_session: None,
+        max_age_days: 7,
+    })
+    .unwrap();
+
+    let remaining = db.get_recent_metrics(10).unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].pid, 2);
 }
\ No newline at end of file